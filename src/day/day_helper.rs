@@ -1,5 +1,8 @@
 use chrono::{Datelike, Days, NaiveDate, NaiveDateTime};
 
+use crate::error::DateUtilsError;
+use crate::rounding::{NANOS_PER_DAY, Rounding, fraction, split_duration};
+
 /// English: The helper of day
 ///
 /// 中文: 日助手
@@ -37,6 +40,16 @@ pub trait DayHelper {
     /// 中文: 计算日期之间的整天数。
     fn diff_days(&self, other: &Self) -> i64;
 
+    /// English: Get the number of days between the given dates with the given rounding method.
+    ///
+    /// 中文: 按照指定的取整方式计算两个日期之间的天数
+    fn diff_days_with(&self, other: &Self, rounding: Rounding) -> i64;
+
+    /// English: Get the number of days between the given dates, including the fractional part.
+    ///
+    /// 中文: 计算两个日期之间的天数，包含小数部分
+    fn diff_days_f64(&self, other: &Self) -> f64;
+
     /// English: Get the end of one day
     ///
     /// 中文: 获取一日的结束时间
@@ -61,6 +74,24 @@ pub trait DayHelper {
     fn sub_days_opt(&self, n: u64) -> Option<Self>
     where
         Self: Sized;
+    /// English: Add the specified number of days, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 添加指定的天数，溢出时返回错误而不是panic
+    fn try_add_days(&self, n: u64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.add_days_opt(n).ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Sub the specified number of days, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 减去指定的天数，溢出时返回错误而不是panic
+    fn try_sub_days(&self, n: u64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.sub_days_opt(n).ok_or(DateUtilsError::OutOfRange)
+    }
 }
 
 impl DayHelper for NaiveDate {
@@ -92,6 +123,16 @@ impl DayHelper for NaiveDate {
         duration.num_days()
     }
 
+    fn diff_days_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_DAY);
+        rounding.apply(whole, remainder, NANOS_PER_DAY)
+    }
+
+    fn diff_days_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_DAY);
+        fraction(whole, remainder, NANOS_PER_DAY)
+    }
+
     fn end_of_day(&self) -> Self {
         *self
     }
@@ -144,6 +185,16 @@ impl DayHelper for NaiveDateTime {
         duration.num_days()
     }
 
+    fn diff_days_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_DAY);
+        rounding.apply(whole, remainder, NANOS_PER_DAY)
+    }
+
+    fn diff_days_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_DAY);
+        fraction(whole, remainder, NANOS_PER_DAY)
+    }
+
     fn end_of_day(&self) -> Self {
         self.date().and_hms_opt(23, 59, 59).unwrap()
     }
@@ -234,6 +285,27 @@ mod tests {
         assert_eq!(result, actual);
     }
 
+    #[test]
+    fn test_date_try_add_days() {
+        let date = get_date(2000, 1, 1).unwrap();
+        assert_eq!(date.try_add_days(8), Ok(get_date(2000, 1, 9).unwrap()));
+        assert_eq!(
+            NaiveDate::MAX.try_add_days(1),
+            Err(DateUtilsError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_datetime_try_sub_days() {
+        let datetime = get_time_opt(2000, 1, 9, 0, 0, 0).unwrap();
+        let actual = get_time_opt(2000, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(datetime.try_sub_days(8), Ok(actual));
+        assert_eq!(
+            NaiveDateTime::MIN.try_sub_days(1),
+            Err(DateUtilsError::OutOfRange)
+        );
+    }
+
     #[test]
     fn test_date_sub_days() {
         let date = get_date(2000, 1, 9);
@@ -282,6 +354,18 @@ mod tests {
         assert_eq!(result, 7);
     }
 
+    #[test]
+    fn test_datetime_diff_days_with() {
+        let one = get_time_opt(2000, 1, 1, 12, 0, 0).unwrap();
+        let other = get_time_opt(2000, 1, 9, 0, 0, 0).unwrap();
+        assert_eq!(other.diff_days_with(&one, Rounding::Trunc), 7);
+        assert_eq!(other.diff_days_with(&one, Rounding::Floor), 7);
+        assert_eq!(other.diff_days_with(&one, Rounding::Ceil), 8);
+        assert_eq!(other.diff_days_with(&one, Rounding::Round), 8);
+        assert_eq!(one.diff_days_with(&other, Rounding::Floor), -8);
+        assert_eq!(one.diff_days_f64(&other), -7.5);
+    }
+
     #[test]
     fn test_date_diff_calendar_days() {
         let one = get_date(2000, 1, 9);
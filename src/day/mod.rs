@@ -3,9 +3,11 @@ mod daytime_helper;
 mod today;
 mod tomorrow;
 mod yesterday;
+mod zoned;
 
 pub use day_helper::DayHelper;
 pub use daytime_helper::DayTimeHelper;
 pub use today::*;
 pub use tomorrow::*;
 pub use yesterday::*;
+pub use zoned::ZonedDayHelper;
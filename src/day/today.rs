@@ -1,6 +1,6 @@
 use super::DayHelper;
 use crate::utils::utc_now;
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, TimeZone};
 
 pub trait TodayHelper<T> {
     /// English: Return the end of today
@@ -11,6 +11,10 @@ pub trait TodayHelper<T> {
     ///
     /// 中文：判断是否是今天
     fn is_today(&self) -> bool;
+    /// English: Is the given date today in the time zone
+    ///
+    /// 中文：判断是否是某个时区的今天
+    fn is_today_in<Tz: TimeZone>(&self, zone: &Tz) -> bool;
     /// English: Return the start of today
     ///
     /// 中文：返回今天的开始时间
@@ -28,6 +32,10 @@ impl TodayHelper<&Self> for NaiveDateTime {
         today.is_same_day(&other)
     }
 
+    fn is_today_in<Tz: TimeZone>(&self, zone: &Tz) -> bool {
+        today_in(zone).is_same_day(&self.date())
+    }
+
     fn begin_of_today() -> NaiveDateTime {
         begin_of_today()
     }
@@ -42,6 +50,10 @@ impl TodayHelper<&Self> for NaiveDate {
         today().is_same_day(self)
     }
 
+    fn is_today_in<Tz: TimeZone>(&self, zone: &Tz) -> bool {
+        today_in(zone).is_same_day(self)
+    }
+
     fn begin_of_today() -> NaiveDateTime {
         begin_of_today()
     }
@@ -54,6 +66,13 @@ pub fn today() -> NaiveDate {
     utc_now().naive_local().date()
 }
 
+/// English: Return the date of today in the time zone
+///
+/// 中文：返回某个时区今天的日期
+pub fn today_in<Tz: TimeZone>(zone: &Tz) -> NaiveDate {
+    utc_now().with_timezone(zone).date_naive()
+}
+
 /// English: Return the start of today
 ///
 /// 中文：返回今天的开始时间
@@ -72,6 +91,7 @@ pub fn end_of_today() -> NaiveDateTime {
 mod tests {
     use super::*;
     use crate::test::get_time;
+    use chrono::{FixedOffset, Utc};
 
     #[test]
     fn test_is_today_true() {
@@ -85,6 +105,18 @@ mod tests {
         assert!(!date.is_today());
     }
 
+    #[test]
+    fn test_is_today_in() {
+        let east = FixedOffset::east_opt(8 * 3600).unwrap();
+        let west = FixedOffset::west_opt(5 * 3600).unwrap();
+        let date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        assert!(date.is_today_in(&east));
+        assert!(!date.is_today_in(&west));
+        let date = get_time(1999, 12, 31, 20, 0, 0);
+        assert!(date.is_today_in(&west));
+        assert!(!date.is_today_in(&Utc));
+    }
+
     #[test]
     fn test_begin_of_today() {
         let today = NaiveDate::begin_of_today();
@@ -1,4 +1,4 @@
-use chrono::{Days, NaiveDate, NaiveDateTime};
+use chrono::{Days, NaiveDate, NaiveDateTime, TimeZone};
 
 use crate::utils::utc_now;
 
@@ -9,6 +9,10 @@ pub trait TomorrowHelper {
     ///
     /// 中文：是否是明天
     fn is_tomorrow(&self) -> bool;
+    /// English: Is the given date tomorrow in the time zone
+    ///
+    /// 中文：判断是否是某个时区的明天
+    fn is_tomorrow_in<Tz: TimeZone>(&self, zone: &Tz) -> bool;
     /// English: Return the start of tomorrow
     ///
     /// 中文：返回明天的开始时间
@@ -24,6 +28,10 @@ impl TomorrowHelper for NaiveDate {
         tomorrow().is_same_day(self)
     }
 
+    fn is_tomorrow_in<Tz: TimeZone>(&self, zone: &Tz) -> bool {
+        tomorrow_in(zone).is_same_day(self)
+    }
+
     fn begin_of_tomorrow() -> NaiveDateTime {
         begin_of_tomorrow()
     }
@@ -38,6 +46,10 @@ impl TomorrowHelper for NaiveDateTime {
         tomorrow().is_same_day(&self.date())
     }
 
+    fn is_tomorrow_in<Tz: TimeZone>(&self, zone: &Tz) -> bool {
+        tomorrow_in(zone).is_same_day(&self.date())
+    }
+
     fn begin_of_tomorrow() -> NaiveDateTime {
         begin_of_tomorrow()
     }
@@ -57,6 +69,17 @@ pub fn tomorrow() -> NaiveDate {
         .unwrap()
 }
 
+/// English: Return the date of tomorrow in the time zone
+///
+/// 中文: 返回某个时区明天的日期
+pub fn tomorrow_in<Tz: TimeZone>(zone: &Tz) -> NaiveDate {
+    utc_now()
+        .with_timezone(zone)
+        .date_naive()
+        .checked_add_days(Days::new(1))
+        .unwrap()
+}
+
 /// English: Return the start of tomorrow
 ///
 /// 中文：返回明天的开始时间
@@ -75,6 +98,7 @@ pub fn end_of_tomorrow() -> NaiveDateTime {
 mod tests {
     use super::*;
     use crate::test::get_time;
+    use chrono::FixedOffset;
 
     #[test]
     fn test_is_tomorrow_true() {
@@ -88,6 +112,17 @@ mod tests {
         assert!(!date.is_tomorrow());
     }
 
+    #[test]
+    fn test_is_tomorrow_in() {
+        let east = FixedOffset::east_opt(8 * 3600).unwrap();
+        let west = FixedOffset::west_opt(5 * 3600).unwrap();
+        let date = NaiveDate::from_ymd_opt(2000, 1, 2).unwrap();
+        assert!(date.is_tomorrow_in(&east));
+        assert!(!date.is_tomorrow_in(&west));
+        let date = get_time(2000, 1, 1, 12, 0, 0);
+        assert!(date.is_tomorrow_in(&west));
+    }
+
     #[test]
     fn test_begin_of_tomorrow() {
         let tomorrow = NaiveDate::begin_of_tomorrow();
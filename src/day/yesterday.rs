@@ -1,4 +1,4 @@
-use chrono::{Days, NaiveDate, NaiveDateTime};
+use chrono::{Days, NaiveDate, NaiveDateTime, TimeZone};
 
 use crate::utils::utc_now;
 
@@ -9,6 +9,10 @@ pub trait YesterdayHelper {
     ///
     /// 中文：判断是否是昨天
     fn is_yesterday(&self) -> bool;
+    /// English: Is the given date yesterday in the time zone
+    ///
+    /// 中文：判断是否是某个时区的昨天
+    fn is_yesterday_in<Tz: TimeZone>(&self, zone: &Tz) -> bool;
     /// English: Return the start of yesterday
     ///
     /// 中文：返回昨天的开始时间
@@ -24,6 +28,10 @@ impl YesterdayHelper for NaiveDate {
         yesterday().is_same_day(self)
     }
 
+    fn is_yesterday_in<Tz: TimeZone>(&self, zone: &Tz) -> bool {
+        yesterday_in(zone).is_same_day(self)
+    }
+
     fn begin_of_yesterday() -> NaiveDateTime {
         begin_of_yesterday()
     }
@@ -38,6 +46,10 @@ impl YesterdayHelper for NaiveDateTime {
         yesterday().is_same_day(&self.date())
     }
 
+    fn is_yesterday_in<Tz: TimeZone>(&self, zone: &Tz) -> bool {
+        yesterday_in(zone).is_same_day(&self.date())
+    }
+
     fn begin_of_yesterday() -> NaiveDateTime {
         begin_of_yesterday()
     }
@@ -53,6 +65,17 @@ pub fn yesterday() -> NaiveDate {
         .map(|date| date.date_naive())
         .unwrap()
 }
+
+/// English: Return the date of yesterday in the time zone
+///
+/// 中文: 返回某个时区昨天的日期
+pub fn yesterday_in<Tz: TimeZone>(zone: &Tz) -> NaiveDate {
+    utc_now()
+        .with_timezone(zone)
+        .date_naive()
+        .checked_sub_days(Days::new(1))
+        .unwrap()
+}
 /// English: Return the start of yesterday
 ///
 /// 中文：返回昨天的开始时间
@@ -69,6 +92,18 @@ fn end_of_yesterday() -> NaiveDateTime {
 mod tess {
     use super::*;
     use crate::test::get_time;
+    use chrono::FixedOffset;
+
+    #[test]
+    fn test_is_yesterday_in() {
+        let east = FixedOffset::east_opt(8 * 3600).unwrap();
+        let west = FixedOffset::west_opt(5 * 3600).unwrap();
+        let date = NaiveDate::from_ymd_opt(1999, 12, 31).unwrap();
+        assert!(date.is_yesterday_in(&east));
+        assert!(!date.is_yesterday_in(&west));
+        let date = get_time(1999, 12, 30, 12, 0, 0);
+        assert!(date.is_yesterday_in(&west));
+    }
 
     #[test]
     fn test_is_yesterday_true() {
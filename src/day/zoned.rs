@@ -0,0 +1,307 @@
+use chrono::{DateTime, Datelike, Duration, TimeZone};
+
+use super::DayHelper;
+use crate::dst::{AmbiguityPolicy, GapPolicy, resolve_local};
+use crate::error::DateUtilsError;
+use crate::rounding::{NANOS_PER_DAY, Rounding, fraction, nanos, split_duration};
+
+/// English: The helper of day for datetimes in a time zone with daylight saving time, where a
+/// calendar day may be 23 or 25 hours long.
+///
+/// 中文: 有夏令时的时区中时间的日助手，此时一个日历日可能是23或25小时
+pub trait ZonedDayHelper: Sized {
+    /// English: Add calendar days and keep the local time, resolving the local time with the policies
+    ///
+    /// 中文: 添加日历天数并保持本地时间不变，按照给定的策略处理不存在或出现两次的本地时间
+    fn add_days_with(
+        &self,
+        n: u64,
+        gap: GapPolicy,
+        ambiguity: AmbiguityPolicy,
+    ) -> Result<Self, DateUtilsError>;
+
+    /// English: Sub calendar days and keep the local time, resolving the local time with the policies
+    ///
+    /// 中文: 减去日历天数并保持本地时间不变，按照给定的策略处理不存在或出现两次的本地时间
+    fn sub_days_with(
+        &self,
+        n: u64,
+        gap: GapPolicy,
+        ambiguity: AmbiguityPolicy,
+    ) -> Result<Self, DateUtilsError>;
+
+    /// English: Get the number of elapsed 24-hour spans between two datetimes, truncated towards zero
+    ///
+    /// 中文: 计算两个时间之间经过的24小时的个数，向零截断
+    fn diff_elapsed_days(&self, other: &Self) -> i64;
+
+    /// English: Get the length of the local day, such as 23 hours on the day daylight saving time starts
+    ///
+    /// 中文: 获取本地日的长度，如夏令时开始当天为23小时
+    fn day_length(&self) -> Duration;
+}
+
+impl<Tz: TimeZone> ZonedDayHelper for DateTime<Tz> {
+    fn add_days_with(
+        &self,
+        n: u64,
+        gap: GapPolicy,
+        ambiguity: AmbiguityPolicy,
+    ) -> Result<Self, DateUtilsError> {
+        let days = i64::try_from(n).map_err(|_| DateUtilsError::OutOfRange)?;
+        shift_days(self, days, gap, ambiguity)
+    }
+
+    fn sub_days_with(
+        &self,
+        n: u64,
+        gap: GapPolicy,
+        ambiguity: AmbiguityPolicy,
+    ) -> Result<Self, DateUtilsError> {
+        let days = i64::try_from(n).map_err(|_| DateUtilsError::OutOfRange)?;
+        shift_days(self, -days, gap, ambiguity)
+    }
+
+    fn diff_elapsed_days(&self, other: &Self) -> i64 {
+        (self.naive_utc() - other.naive_utc()).num_days()
+    }
+
+    fn day_length(&self) -> Duration {
+        let begin = self.begin_of_day();
+        begin.add_days(1).begin_of_day() - begin
+    }
+}
+
+impl<Tz: TimeZone> DayHelper for DateTime<Tz> {
+    fn add_days(&self, n: u64) -> Self {
+        self.add_days_opt(n).unwrap()
+    }
+
+    fn add_days_opt(&self, n: u64) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.add_days_with(n, GapPolicy::default(), AmbiguityPolicy::default())
+            .ok()
+    }
+
+    fn begin_of_day(&self) -> Self {
+        let midnight = self.date_naive().and_hms_opt(0, 0, 0).unwrap();
+        resolve_local(
+            &self.timezone(),
+            &midnight,
+            GapPolicy::ShiftForward,
+            AmbiguityPolicy::Earliest,
+        )
+        .unwrap()
+    }
+
+    fn day_of_year(&self) -> u32 {
+        self.ordinal()
+    }
+
+    fn diff_calendar_days(&self, other: &Self) -> i64 {
+        self.date_naive().diff_calendar_days(&other.date_naive())
+    }
+
+    fn diff_days(&self, other: &Self) -> i64 {
+        split_days(self, other).0
+    }
+
+    fn diff_days_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder, unit) = split_days(self, other);
+        rounding.apply(whole, remainder, unit)
+    }
+
+    fn diff_days_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder, unit) = split_days(self, other);
+        fraction(whole, remainder, unit)
+    }
+
+    fn end_of_day(&self) -> Self {
+        self.add_days(1).begin_of_day() - Duration::seconds(1)
+    }
+
+    fn is_same_day(&self, other: &Self) -> bool {
+        self.date_naive() == other.date_naive()
+    }
+
+    fn sub_days(&self, n: u64) -> Self
+    where
+        Self: Sized,
+    {
+        self.sub_days_opt(n).unwrap()
+    }
+
+    fn sub_days_opt(&self, n: u64) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.sub_days_with(n, GapPolicy::default(), AmbiguityPolicy::default())
+            .ok()
+    }
+}
+
+/// 按照日历天数移动并保持本地时间不变
+fn shift_days<Tz: TimeZone>(
+    time: &DateTime<Tz>,
+    days: i64,
+    gap: GapPolicy,
+    ambiguity: AmbiguityPolicy,
+) -> Result<DateTime<Tz>, DateUtilsError> {
+    let local = time.naive_local();
+    let target = local
+        .checked_add_signed(Duration::try_days(days).ok_or(DateUtilsError::OutOfRange)?)
+        .ok_or(DateUtilsError::OutOfRange)?;
+    resolve_local(&time.timezone(), &target, gap, ambiguity)
+}
+
+/// 计算完整的日历天数，返回向零截断的天数、余数（纳秒）以及余数所在那一天的长度（纳秒）
+fn split_days<Tz: TimeZone>(this: &DateTime<Tz>, other: &DateTime<Tz>) -> (i64, i128, i128) {
+    let shifted = |days: i64| {
+        shift_days(
+            other,
+            days,
+            GapPolicy::default(),
+            AmbiguityPolicy::default(),
+        )
+    };
+    let step = if this >= other { 1 } else { -1 };
+    let mut whole = this.diff_calendar_days(other);
+    let mut start = shifted(whole);
+    // 本地时间早于起点时，最后一天不完整
+    let incomplete = |start: &DateTime<Tz>| if step > 0 { start > this } else { start < this };
+    while whole != 0 && start.as_ref().is_ok_and(incomplete) {
+        whole -= step;
+        start = shifted(whole);
+    }
+    match (start, shifted(whole + step)) {
+        (Ok(start), Ok(end)) => (
+            whole,
+            nanos(this.naive_utc() - start.naive_utc()),
+            nanos(end.naive_utc() - start.naive_utc()).abs(),
+        ),
+        // 超出可以表示的范围时按照24小时计算
+        _ => {
+            let (whole, remainder) =
+                split_duration(this.naive_utc() - other.naive_utc(), NANOS_PER_DAY);
+            (whole, remainder, NANOS_PER_DAY)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tz::PosixTz;
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn zone() -> PosixTz {
+        "EST5EDT,M3.2.0,M11.1.0".parse().unwrap()
+    }
+
+    fn local(month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn at(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<PosixTz> {
+        zone()
+            .from_local_datetime(&local(month, day, hour, minute))
+            .earliest()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_add_days_spring_forward() {
+        // 2024年3月10日02:00变为03:00
+        let start = at(3, 9, 12, 0);
+        let next = start.add_days(1);
+        assert_eq!(next.naive_local(), local(3, 10, 12, 0));
+        assert_eq!(next.naive_utc() - start.naive_utc(), Duration::hours(23));
+        assert_eq!(next.sub_days(1), start);
+
+        let start = at(3, 9, 2, 30);
+        assert_eq!(start.add_days(1).naive_local(), local(3, 10, 3, 30));
+        let earlier = start.add_days_with(1, GapPolicy::Earlier, AmbiguityPolicy::Earliest);
+        assert_eq!(earlier.unwrap().naive_local(), local(3, 10, 1, 30));
+        assert_eq!(
+            start.add_days_with(1, GapPolicy::Error, AmbiguityPolicy::Earliest),
+            Err(DateUtilsError::SkippedLocalTime(local(3, 10, 2, 30)))
+        );
+    }
+
+    #[test]
+    fn test_add_days_fall_back() {
+        // 2024年11月3日02:00变回01:00
+        let start = at(11, 2, 1, 30);
+        let earliest = start.add_days(1);
+        assert_eq!(earliest.naive_local(), local(11, 3, 1, 30));
+        assert_eq!(
+            earliest.naive_utc() - start.naive_utc(),
+            Duration::hours(24)
+        );
+        let latest = start
+            .add_days_with(1, GapPolicy::ShiftForward, AmbiguityPolicy::Latest)
+            .unwrap();
+        assert_eq!(latest.naive_local(), local(11, 3, 1, 30));
+        assert_eq!(latest.naive_utc() - start.naive_utc(), Duration::hours(25));
+        assert_eq!(
+            start.add_days_with(1, GapPolicy::ShiftForward, AmbiguityPolicy::Error),
+            Err(DateUtilsError::AmbiguousLocalTime(local(11, 3, 1, 30)))
+        );
+    }
+
+    #[test]
+    fn test_diff_days() {
+        let start = at(3, 9, 12, 0);
+        let end = at(3, 10, 12, 0);
+        assert_eq!(end.diff_days(&start), 1);
+        assert_eq!(end.diff_elapsed_days(&start), 0);
+        assert_eq!(start.diff_days(&end), -1);
+        assert_eq!(at(3, 10, 11, 0).diff_days(&start), 0);
+        assert_eq!(at(3, 10, 0, 0).diff_days_f64(&at(3, 9, 0, 0)), 1.0);
+
+        let start = at(11, 2, 12, 0);
+        let end = at(11, 3, 12, 0);
+        assert_eq!(end.diff_days(&start), 1);
+        assert_eq!(end.diff_elapsed_days(&start), 1);
+        assert_eq!(end.diff_calendar_days(&start), 1);
+        // 11月3日00:00到11月4日00:00为完整的一天（25小时），剩余的30分钟按照11月4日的24小时计算
+        let half = at(11, 4, 0, 30).diff_days_f64(&at(11, 3, 0, 0));
+        assert_eq!(half, 1.0 + 0.5 / 24.0);
+        // 11月3日有25小时，00:00到12:30实际经过13.5小时
+        let part = at(11, 3, 12, 30).diff_days_f64(&at(11, 3, 0, 0));
+        assert_eq!(part, 13.5 / 25.0);
+        assert_eq!(
+            at(11, 3, 12, 30).diff_days_with(&at(11, 3, 0, 0), Rounding::Round),
+            1
+        );
+    }
+
+    #[test]
+    fn test_begin_and_end_of_day() {
+        let time = at(3, 10, 12, 0);
+        assert_eq!(time.begin_of_day().naive_local(), local(3, 10, 0, 0));
+        assert_eq!(
+            time.end_of_day().naive_local(),
+            local(3, 10, 23, 59) + Duration::seconds(59)
+        );
+        assert_eq!(time.day_length(), Duration::hours(23));
+        assert_eq!(at(11, 3, 12, 0).day_length(), Duration::hours(25));
+        assert_eq!(at(7, 1, 12, 0).day_length(), Duration::hours(24));
+        assert!(time.is_same_day(&at(3, 10, 0, 0)));
+        assert_eq!(time.day_of_year(), 70);
+    }
+
+    #[test]
+    fn test_midnight_gap() {
+        // 00:00变为01:00时，一天从01:00开始
+        let zone: PosixTz = "<-04>4<-03>,M9.1.6/24,M4.1.6/24".parse().unwrap();
+        let time = zone.from_local_datetime(&local(9, 8, 12, 0)).unwrap();
+        assert_eq!(time.begin_of_day().naive_local(), local(9, 8, 1, 0));
+        assert_eq!(time.day_length(), Duration::hours(23));
+    }
+}
@@ -1,5 +1,6 @@
 use chrono::{Datelike, NaiveDate, NaiveDateTime};
 use crate::day::DayHelper;
+use crate::overflow::OverflowPolicy;
 use crate::year::YearHelper;
 
 pub trait DecadeHelper {
@@ -19,17 +20,138 @@ pub trait DecadeHelper {
     ///
     /// 中文: 获取年代代表
     fn decade(&self) -> i32;
+    /// English: Whether two dates are in the same decade
+    ///
+    /// 中文: 两个日期是否在同一个年代
+    fn is_same_decade(&self, other: &Self) -> bool;
+    /// English: Add decades, February 29th becomes February 28th when the target year is not a leap year
+    ///
+    /// 中文: 增加年代数，目标年份不是闰年时2月29日变为2月28日
+    fn add_decades(&self, n: i32) -> Self;
+    /// English: Add decades, return `None` when the result is out of range
+    ///
+    /// 中文: 增加年代数，结果超出范围时返回`None`
+    fn add_decades_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Subtract decades
+    ///
+    /// 中文: 减少年代数
+    fn sub_decades(&self, n: i32) -> Self;
+    /// English: Subtract decades, return `None` when the result is out of range
+    ///
+    /// 中文: 减少年代数，结果超出范围时返回`None`
+    fn sub_decades_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Get the number of whole decades (ten years) from `other` to `self`
+    ///
+    /// 中文: 获取从`other`到`self`的完整十年数
+    fn diff_decades(&self, other: &Self) -> i32;
+}
+
+/// English: How centuries and millenniums are counted. Years use the proleptic Gregorian calendar
+/// of chrono, in which year 0 is 1 BC.
+///
+/// 中文: 世纪和千年的划分方式。年份使用chrono的前推格里历，其中0年即公元前1年
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CenturyConvention {
+    /// English: Counted from year 1, the 20th century is 1901 to 2000
+    ///
+    /// 中文: 从1年开始计算，20世纪为1901年到2000年
+    #[default]
+    Strict,
+    /// English: Counted by the hundreds digit, the 20th century is 1900 to 1999
+    ///
+    /// 中文: 按照百位数计算，20世纪为1900年到1999年
+    Popular,
+}
+
+pub trait CenturyHelper {
+    /// English: Get the ordinal of the century in the strict convention, e.g. 20 for 2000
+    ///
+    /// 中文: 按照严格划分获取所在的世纪，如2000年为20世纪
+    fn century(&self) -> i32 {
+        self.century_with(CenturyConvention::Strict)
+    }
+    /// English: Get the ordinal of the century in the given convention
+    ///
+    /// 中文: 按照指定的划分方式获取所在的世纪
+    fn century_with(&self, convention: CenturyConvention) -> i32;
+    /// English: Get the start of the century in the strict convention
+    ///
+    /// 中文: 按照严格划分获取世纪的开始时间
+    fn begin_of_century(&self) -> Self
+    where
+        Self: Sized,
+    {
+        self.begin_of_century_with(CenturyConvention::Strict)
+    }
+    /// English: Get the start of the century in the given convention
+    ///
+    /// 中文: 按照指定的划分方式获取世纪的开始时间
+    fn begin_of_century_with(&self, convention: CenturyConvention) -> Self;
+    /// English: Get the end of the century in the strict convention
+    ///
+    /// 中文: 按照严格划分获取世纪的结束时间
+    fn end_of_century(&self) -> Self
+    where
+        Self: Sized,
+    {
+        self.end_of_century_with(CenturyConvention::Strict)
+    }
+    /// English: Get the end of the century in the given convention
+    ///
+    /// 中文: 按照指定的划分方式获取世纪的结束时间
+    fn end_of_century_with(&self, convention: CenturyConvention) -> Self;
+}
+
+pub trait MillenniumHelper {
+    /// English: Get the ordinal of the millennium in the strict convention, e.g. 2 for 2000
+    ///
+    /// 中文: 按照严格划分获取所在的千年，如2000年为第2个千年
+    fn millennium(&self) -> i32 {
+        self.millennium_with(CenturyConvention::Strict)
+    }
+    /// English: Get the ordinal of the millennium in the given convention
+    ///
+    /// 中文: 按照指定的划分方式获取所在的千年
+    fn millennium_with(&self, convention: CenturyConvention) -> i32;
+    /// English: Get the start of the millennium in the strict convention
+    ///
+    /// 中文: 按照严格划分获取千年的开始时间
+    fn begin_of_millennium(&self) -> Self
+    where
+        Self: Sized,
+    {
+        self.begin_of_millennium_with(CenturyConvention::Strict)
+    }
+    /// English: Get the start of the millennium in the given convention
+    ///
+    /// 中文: 按照指定的划分方式获取千年的开始时间
+    fn begin_of_millennium_with(&self, convention: CenturyConvention) -> Self;
+    /// English: Get the end of the millennium in the strict convention
+    ///
+    /// 中文: 按照严格划分获取千年的结束时间
+    fn end_of_millennium(&self) -> Self
+    where
+        Self: Sized,
+    {
+        self.end_of_millennium_with(CenturyConvention::Strict)
+    }
+    /// English: Get the end of the millennium in the given convention
+    ///
+    /// 中文: 按照指定的划分方式获取千年的结束时间
+    fn end_of_millennium_with(&self, convention: CenturyConvention) -> Self;
 }
 
 impl DecadeHelper for NaiveDate {
     fn begin_of_decade(&self) -> Self {
-        let year = get_decade_start(self.year());
-        self.with_year(year).unwrap().begin_of_year()
+        first_day(get_decade_start(self.year()))
     }
 
     fn end_of_decade(&self) -> Self {
-        let year = get_decade_end(self.year());
-        self.with_year(year).unwrap().end_of_year()
+        last_day(get_decade_end(self.year()))
     }
 
     fn last_day_of_decade(&self) -> Self {
@@ -39,26 +161,75 @@ impl DecadeHelper for NaiveDate {
     fn decade(&self) -> i32 {
         get_decade_start(self.year())
     }
+
+    fn is_same_decade(&self, other: &Self) -> bool {
+        self.decade() == other.decade()
+    }
+
+    fn add_decades(&self, n: i32) -> Self {
+        self.add_decades_opt(n).unwrap()
+    }
+
+    fn add_decades_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.add_years_with(n.checked_mul(10)?, OverflowPolicy::Clamp)
+            .ok()
+    }
+
+    fn sub_decades(&self, n: i32) -> Self {
+        self.sub_decades_opt(n).unwrap()
+    }
+
+    fn sub_decades_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.add_decades_opt(n.checked_neg()?)
+    }
+
+    fn diff_decades(&self, other: &Self) -> i32 {
+        self.diff_years(other) / 10
+    }
 }
 
 fn get_decade_start(year: i32) -> i32 {
-    let year = format!("{}0", year / 10);
-    year.parse::<i32>().unwrap()
+    year.div_euclid(10) * 10
 }
 fn get_decade_end(year: i32) -> i32 {
-    let year = format!("{}9", year / 10);
-    year.parse::<i32>().unwrap()
+    get_decade_start(year) + 9
+}
+
+/// 按照划分方式获取`year`所在的长度为`size`年的时期的序号和第一年
+fn span(year: i32, size: i32, convention: CenturyConvention) -> (i32, i32) {
+    match convention {
+        CenturyConvention::Strict => {
+            let index = (year - 1).div_euclid(size);
+            (index + 1, index * size + 1)
+        }
+        CenturyConvention::Popular => {
+            let index = year.div_euclid(size);
+            (index + 1, index * size)
+        }
+    }
+}
+
+fn first_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, 1, 1).unwrap()
+}
+
+fn last_day(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, 12, 31).unwrap()
 }
 
 impl DecadeHelper for NaiveDateTime {
     fn begin_of_decade(&self) -> Self {
-        let year = get_decade_start(self.year());
-        self.with_year(year).unwrap().begin_of_year()
+        self.date().begin_of_decade().and_hms_opt(0, 0, 0).unwrap()
     }
 
     fn end_of_decade(&self) -> Self {
-        let year = get_decade_end(self.year());
-        self.with_year(year).unwrap().end_of_year()
+        self.date().end_of_decade().and_hms_opt(23, 59, 59).unwrap()
     }
 
     fn last_day_of_decade(&self) -> Self {
@@ -68,4 +239,103 @@ impl DecadeHelper for NaiveDateTime {
     fn decade(&self) -> i32 {
         get_decade_start(self.year())
     }
+
+    fn is_same_decade(&self, other: &Self) -> bool {
+        self.decade() == other.decade()
+    }
+
+    fn add_decades(&self, n: i32) -> Self {
+        self.add_decades_opt(n).unwrap()
+    }
+
+    fn add_decades_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.add_years_with(n.checked_mul(10)?, OverflowPolicy::Clamp)
+            .ok()
+    }
+
+    fn sub_decades(&self, n: i32) -> Self {
+        self.sub_decades_opt(n).unwrap()
+    }
+
+    fn sub_decades_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.add_decades_opt(n.checked_neg()?)
+    }
+
+    fn diff_decades(&self, other: &Self) -> i32 {
+        self.diff_years(other) / 10
+    }
+}
+
+impl CenturyHelper for NaiveDate {
+    fn century_with(&self, convention: CenturyConvention) -> i32 {
+        span(self.year(), 100, convention).0
+    }
+
+    fn begin_of_century_with(&self, convention: CenturyConvention) -> Self {
+        first_day(span(self.year(), 100, convention).1)
+    }
+
+    fn end_of_century_with(&self, convention: CenturyConvention) -> Self {
+        last_day(span(self.year(), 100, convention).1 + 99)
+    }
+}
+
+impl CenturyHelper for NaiveDateTime {
+    fn century_with(&self, convention: CenturyConvention) -> i32 {
+        self.date().century_with(convention)
+    }
+
+    fn begin_of_century_with(&self, convention: CenturyConvention) -> Self {
+        self.date()
+            .begin_of_century_with(convention)
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn end_of_century_with(&self, convention: CenturyConvention) -> Self {
+        self.date()
+            .end_of_century_with(convention)
+            .and_hms_opt(23, 59, 59)
+            .unwrap()
+    }
+}
+
+impl MillenniumHelper for NaiveDate {
+    fn millennium_with(&self, convention: CenturyConvention) -> i32 {
+        span(self.year(), 1000, convention).0
+    }
+
+    fn begin_of_millennium_with(&self, convention: CenturyConvention) -> Self {
+        first_day(span(self.year(), 1000, convention).1)
+    }
+
+    fn end_of_millennium_with(&self, convention: CenturyConvention) -> Self {
+        last_day(span(self.year(), 1000, convention).1 + 999)
+    }
+}
+
+impl MillenniumHelper for NaiveDateTime {
+    fn millennium_with(&self, convention: CenturyConvention) -> i32 {
+        self.date().millennium_with(convention)
+    }
+
+    fn begin_of_millennium_with(&self, convention: CenturyConvention) -> Self {
+        self.date()
+            .begin_of_millennium_with(convention)
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn end_of_millennium_with(&self, convention: CenturyConvention) -> Self {
+        self.date()
+            .end_of_millennium_with(convention)
+            .and_hms_opt(23, 59, 59)
+            .unwrap()
+    }
 }
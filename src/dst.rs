@@ -0,0 +1,76 @@
+use chrono::{DateTime, Duration, MappedLocalTime, NaiveDateTime, Offset, TimeZone};
+
+use crate::error::DateUtilsError;
+
+/// English: The policy for a local time which is skipped when the clocks move forward, such as
+/// 02:30 on the day daylight saving time starts.
+///
+/// 中文: 时钟向前调整时，不存在的本地时间的处理策略，如夏令时开始当天的02:30
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GapPolicy {
+    /// English: Shift forward by the length of the gap, e.g. 02:30 becomes 03:30.
+    ///
+    /// 中文: 向后移动间隔的长度，如02:30变为03:30
+    #[default]
+    ShiftForward,
+    /// English: Shift back by the length of the gap, e.g. 02:30 becomes 01:30.
+    ///
+    /// 中文: 向前移动间隔的长度，如02:30变为01:30
+    Earlier,
+    /// English: Return an error.
+    ///
+    /// 中文: 返回错误
+    Error,
+}
+
+/// English: The policy for a local time which occurs twice when the clocks move backward, such as
+/// 01:30 on the day daylight saving time ends.
+///
+/// 中文: 时钟向后调整时，出现两次的本地时间的处理策略，如夏令时结束当天的01:30
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AmbiguityPolicy {
+    /// English: Use the earliest of the two times, which still has the old offset.
+    ///
+    /// 中文: 使用两个时间中较早的一个，即仍使用旧偏移量的时间
+    #[default]
+    Earliest,
+    /// English: Use the latest of the two times, which has the new offset.
+    ///
+    /// 中文: 使用两个时间中较晚的一个，即使用新偏移量的时间
+    Latest,
+    /// English: Return an error.
+    ///
+    /// 中文: 返回错误
+    Error,
+}
+
+/// 按照给定的策略将本地时间转换为时区中的时间
+pub(crate) fn resolve_local<Tz: TimeZone>(
+    zone: &Tz,
+    local: &NaiveDateTime,
+    gap: GapPolicy,
+    ambiguity: AmbiguityPolicy,
+) -> Result<DateTime<Tz>, DateUtilsError> {
+    match zone.from_local_datetime(local) {
+        MappedLocalTime::Single(time) => Ok(time),
+        MappedLocalTime::Ambiguous(earliest, latest) => match ambiguity {
+            AmbiguityPolicy::Earliest => Ok(earliest),
+            AmbiguityPolicy::Latest => Ok(latest),
+            AmbiguityPolicy::Error => Err(DateUtilsError::AmbiguousLocalTime(*local)),
+        },
+        MappedLocalTime::None => {
+            // 间隔之前使用旧偏移量，之后使用新偏移量，假设相邻两次变化的间隔超过一天
+            let near = match gap {
+                GapPolicy::ShiftForward => local.checked_sub_signed(Duration::days(1)),
+                GapPolicy::Earlier => local.checked_add_signed(Duration::days(1)),
+                GapPolicy::Error => return Err(DateUtilsError::SkippedLocalTime(*local)),
+            }
+            .ok_or(DateUtilsError::OutOfRange)?;
+            let offset = zone.offset_from_utc_datetime(&near).fix();
+            let utc = local
+                .checked_sub_offset(offset)
+                .ok_or(DateUtilsError::OutOfRange)?;
+            Ok(zone.from_utc_datetime(&utc))
+        }
+    }
+}
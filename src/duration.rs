@@ -0,0 +1,303 @@
+use chrono::Duration;
+
+use crate::error::DateUtilsError;
+use crate::rounding::{
+    NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MILLISECOND, NANOS_PER_MINUTE, NANOS_PER_SECOND,
+    NANOS_PER_WEEK, nanos,
+};
+
+/// English: The style of a formatted duration.
+///
+/// 中文: 格式化时长的样式
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// English: Compact style, such as `1h30m`
+    ///
+    /// 中文: 紧凑样式，如`1h30m`
+    #[default]
+    Compact,
+    /// English: Long style, such as `1 hour 30 minutes`
+    ///
+    /// 中文: 完整样式，如`1 hour 30 minutes`
+    Long,
+    /// English: Chinese style, such as `1小时30分钟`
+    ///
+    /// 中文: 中文样式，如`1小时30分钟`
+    Chinese,
+}
+
+/// English: The options of [`format_duration`].
+///
+/// 中文: [`format_duration`]的选项
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DurationFormat {
+    /// English: The style of the output
+    ///
+    /// 中文: 输出的样式
+    pub style: DurationStyle,
+    /// English: The maximum number of units in the output, the smaller units are truncated.
+    /// `None` means no limit.
+    ///
+    /// 中文: 输出中最多包含的单位数，更小的单位会被截断。`None`表示不限制
+    pub precision: Option<usize>,
+}
+
+impl DurationFormat {
+    /// English: Create the options with the given style and no precision limit
+    ///
+    /// 中文: 使用给定的样式创建选项，不限制精度
+    pub fn new(style: DurationStyle) -> DurationFormat {
+        DurationFormat {
+            style,
+            precision: None,
+        }
+    }
+
+    /// English: Limit the maximum number of units in the output
+    ///
+    /// 中文: 限制输出中最多包含的单位数
+    pub fn with_precision(self, precision: usize) -> DurationFormat {
+        DurationFormat {
+            precision: Some(precision),
+            ..self
+        }
+    }
+}
+
+/// 格式化时使用的单位：纳秒数、紧凑样式、完整样式（单数）、中文样式
+const FORMAT_UNITS: [(i128, &str, &str, &str); 5] = [
+    (NANOS_PER_DAY, "d", "day", "天"),
+    (NANOS_PER_HOUR, "h", "hour", "小时"),
+    (NANOS_PER_MINUTE, "m", "minute", "分钟"),
+    (NANOS_PER_SECOND, "s", "second", "秒"),
+    (NANOS_PER_MILLISECOND, "ms", "millisecond", "毫秒"),
+];
+
+/// English: Format the duration in a human readable way, the part smaller than a millisecond is truncated.
+///
+/// 中文: 将时长格式化为易读的形式，小于一毫秒的部分会被截断
+pub fn format_duration(duration: Duration, format: DurationFormat) -> String {
+    let total = nanos(duration);
+    let mut rest = total.abs();
+    let mut parts = Vec::new();
+    for (unit, compact, long, chinese) in FORMAT_UNITS {
+        let value = rest / unit;
+        rest %= unit;
+        if value == 0 {
+            continue;
+        }
+        parts.push(match format.style {
+            DurationStyle::Compact => format!("{}{}", value, compact),
+            DurationStyle::Long if value == 1 => format!("{} {}", value, long),
+            DurationStyle::Long => format!("{} {}s", value, long),
+            DurationStyle::Chinese => format!("{}{}", value, chinese),
+        });
+    }
+    if let Some(precision) = format.precision {
+        parts.truncate(precision.max(1));
+    }
+    if parts.is_empty() {
+        return match format.style {
+            DurationStyle::Compact => "0s".to_string(),
+            DurationStyle::Long => "0 seconds".to_string(),
+            DurationStyle::Chinese => "0秒".to_string(),
+        };
+    }
+    let separator = match format.style {
+        DurationStyle::Long => " ",
+        _ => "",
+    };
+    let sign = if total < 0 { "-" } else { "" };
+    format!("{}{}", sign, parts.join(separator))
+}
+
+/// 解析单位名称，返回单位对应的纳秒数
+fn parse_unit(unit: &str) -> Option<i128> {
+    let nanos = match unit.to_lowercase().as_str() {
+        "ns" | "nanosecond" | "nanoseconds" | "纳秒" => 1,
+        "us" | "µs" | "μs" | "microsecond" | "microseconds" | "微秒" => 1000,
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" | "毫秒" => {
+            NANOS_PER_MILLISECOND
+        }
+        "s" | "sec" | "secs" | "second" | "seconds" | "秒" | "秒钟" => NANOS_PER_SECOND,
+        "m" | "min" | "mins" | "minute" | "minutes" | "分" | "分钟" => NANOS_PER_MINUTE,
+        "h" | "hr" | "hrs" | "hour" | "hours" | "时" | "小时" | "个小时" => NANOS_PER_HOUR,
+        "d" | "day" | "days" | "天" | "日" => NANOS_PER_DAY,
+        "w" | "week" | "weeks" | "周" | "星期" | "个星期" => NANOS_PER_WEEK,
+        _ => return None,
+    };
+    Some(nanos)
+}
+
+/// 解析形如`3.5`的数字，并乘以单位对应的纳秒数
+pub(crate) fn parse_amount(number: &str, unit: i128) -> Option<i128> {
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let integer = if integer.is_empty() {
+        0
+    } else {
+        integer.parse::<i128>().ok()?
+    };
+    let mut amount = integer.checked_mul(unit)?;
+    let mut scale = unit;
+    for digit in fraction.chars() {
+        scale /= 10;
+        amount = amount.checked_add(digit.to_digit(10)? as i128 * scale)?;
+    }
+    Some(amount)
+}
+
+/// English: Parse a human readable duration, such as `1h30m`, `1h2m3.5s`, `1 hour 30 minutes`,
+/// `2 days, 3 hours` or `1小时30分钟`. A leading `-` makes the duration negative.
+///
+/// 中文: 解析易读形式的时长，如`1h30m`、`1h2m3.5s`、`1 hour 30 minutes`、`2 days, 3 hours`或`1小时30分钟`。
+/// 以`-`开头时表示负的时长
+pub fn parse_duration(input: &str) -> Result<Duration, DateUtilsError> {
+    let error = || DateUtilsError::Parse(input.to_string());
+    let text = input.trim();
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    if text == "0" {
+        return Ok(Duration::zero());
+    }
+    let mut total: i128 = 0;
+    let mut chars = text.chars().peekable();
+    let mut matched = false;
+    loop {
+        while chars
+            .next_if(|c| c.is_whitespace() || *c == ',' || *c == '，')
+            .is_some()
+        {}
+        if chars.peek().is_none() {
+            break;
+        }
+        let mut number = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+            number.push(c);
+        }
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut unit = String::new();
+        while let Some(c) = chars.next_if(|c| c.is_alphabetic()) {
+            unit.push(c);
+        }
+        if number.is_empty() && unit == "and" {
+            continue;
+        }
+        let unit = parse_unit(&unit).ok_or_else(error)?;
+        let amount = parse_amount(&number, unit).ok_or_else(error)?;
+        total = total
+            .checked_add(amount)
+            .ok_or(DateUtilsError::OutOfRange)?;
+        matched = true;
+    }
+    if !matched {
+        return Err(error());
+    }
+    if negative {
+        total = -total;
+    }
+    let seconds = i64::try_from(total.div_euclid(NANOS_PER_SECOND))
+        .map_err(|_| DateUtilsError::OutOfRange)?;
+    let nanos = total.rem_euclid(NANOS_PER_SECOND) as u32;
+    Duration::new(seconds, nanos).ok_or(DateUtilsError::OutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn duration() -> Duration {
+        Duration::days(2) + Duration::hours(3) + Duration::minutes(30) + Duration::milliseconds(250)
+    }
+
+    #[test]
+    fn test_format_compact() {
+        let format = DurationFormat::default();
+        assert_eq!(format_duration(duration(), format), "2d3h30m250ms");
+        assert_eq!(format_duration(Duration::minutes(-90), format), "-1h30m");
+        assert_eq!(format_duration(Duration::zero(), format), "0s");
+    }
+
+    #[test]
+    fn test_format_long() {
+        let format = DurationFormat::new(DurationStyle::Long);
+        assert_eq!(
+            format_duration(Duration::minutes(90), format),
+            "1 hour 30 minutes"
+        );
+        assert_eq!(
+            format_duration(duration(), format.with_precision(2)),
+            "2 days 3 hours"
+        );
+        assert_eq!(
+            format_duration(Duration::nanoseconds(1), format),
+            "0 seconds"
+        );
+    }
+
+    #[test]
+    fn test_format_chinese() {
+        let format = DurationFormat::new(DurationStyle::Chinese);
+        assert_eq!(
+            format_duration(Duration::minutes(90), format),
+            "1小时30分钟"
+        );
+        assert_eq!(
+            format_duration(duration(), format.with_precision(3)),
+            "2天3小时30分钟"
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("1h30m"), Ok(Duration::minutes(90)));
+        assert_eq!(
+            parse_duration("1h2m3.5s"),
+            Ok(Duration::seconds(3723) + Duration::milliseconds(500))
+        );
+        assert_eq!(
+            parse_duration("1 hour 30 minutes"),
+            Ok(Duration::minutes(90))
+        );
+        assert_eq!(parse_duration("2 days, 3 hours"), Ok(Duration::hours(51)));
+        assert_eq!(
+            parse_duration("1 hour and 1 minute"),
+            Ok(Duration::minutes(61))
+        );
+        assert_eq!(parse_duration("1小时30分钟"), Ok(Duration::minutes(90)));
+        assert_eq!(parse_duration("-1.5h"), Ok(Duration::minutes(-90)));
+        assert_eq!(parse_duration(".5s"), Ok(Duration::milliseconds(500)));
+        assert_eq!(parse_duration("0"), Ok(Duration::zero()));
+        assert_eq!(parse_duration("300ms"), Ok(Duration::milliseconds(300)));
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for style in [
+            DurationStyle::Compact,
+            DurationStyle::Long,
+            DurationStyle::Chinese,
+        ] {
+            let text = format_duration(duration(), DurationFormat::new(style));
+            assert_eq!(parse_duration(&text), Ok(duration()));
+        }
+    }
+
+    #[test]
+    fn test_parse_duration_error() {
+        for input in ["", "1", "h", "1x", "1..5s", "1h 30"] {
+            assert_eq!(
+                parse_duration(input),
+                Err(DateUtilsError::Parse(input.to_string()))
+            );
+        }
+        assert_eq!(
+            parse_duration("99999999999999999999d"),
+            Err(DateUtilsError::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use chrono::NaiveDateTime;
+
+/// English: The error type of the date utils.
+///
+/// 中文: 日期工具的错误类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateUtilsError {
+    /// English: The result is out of the range which can be represented.
+    ///
+    /// 中文: 结果超出了可以表示的范围
+    OutOfRange,
+    /// English: The given values do not make up an existing date or time.
+    ///
+    /// 中文: 给定的值无法组成一个存在的日期或时间
+    InvalidDate,
+    /// English: The given value is not a valid UTC offset.
+    ///
+    /// 中文: 给定的值不是一个有效的时区偏移
+    InvalidOffset,
+    /// English: The given input can not be parsed.
+    ///
+    /// 中文: 给定的输入无法被解析
+    Parse(String),
+    /// English: The named time zone can not be found or read.
+    ///
+    /// 中文: 找不到或无法读取指定名称的时区
+    UnknownZone(String),
+    /// English: The local time is skipped by a transition of the time zone, such as the start of
+    /// daylight saving time.
+    ///
+    /// 中文: 本地时间因时区的偏移量变化而不存在，如夏令时开始时
+    SkippedLocalTime(NaiveDateTime),
+    /// English: The local time occurs twice because of a transition of the time zone, such as the
+    /// end of daylight saving time.
+    ///
+    /// 中文: 本地时间因时区的偏移量变化而出现两次，如夏令时结束时
+    AmbiguousLocalTime(NaiveDateTime),
+}
+
+impl Display for DateUtilsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateUtilsError::OutOfRange => write!(f, "the result is out of range"),
+            DateUtilsError::InvalidDate => write!(f, "the date or time does not exist"),
+            DateUtilsError::InvalidOffset => write!(f, "the UTC offset is invalid"),
+            DateUtilsError::Parse(input) => write!(f, "can not parse `{}`", input),
+            DateUtilsError::UnknownZone(name) => write!(f, "unknown time zone `{}`", name),
+            DateUtilsError::SkippedLocalTime(local) => {
+                write!(f, "the local time `{}` is skipped in the time zone", local)
+            }
+            DateUtilsError::AmbiguousLocalTime(local) => {
+                write!(
+                    f,
+                    "the local time `{}` is ambiguous in the time zone",
+                    local
+                )
+            }
+        }
+    }
+}
+
+impl Error for DateUtilsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            DateUtilsError::OutOfRange.to_string(),
+            "the result is out of range"
+        );
+        assert_eq!(
+            DateUtilsError::Parse("2000-13".to_string()).to_string(),
+            "can not parse `2000-13`"
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::DateUtilsError;
+use crate::quarter::Quarter;
+use crate::utils::days_in_month;
+
+/// English: How a fiscal year is named when it spans two calendar years.
+///
+/// 中文: 跨越两个日历年的财年的命名方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiscalYearNaming {
+    /// English: Named after the calendar year in which it starts, e.g. Japan.
+    ///
+    /// 中文: 以开始时所在的日历年命名，如日本
+    StartYear,
+    /// English: Named after the calendar year in which it ends, e.g. the US federal government.
+    ///
+    /// 中文: 以结束时所在的日历年命名，如美国联邦政府
+    EndYear,
+}
+
+/// English: A fiscal calendar whose year starts on an arbitrary day. The fiscal quarters start on the
+/// same day of every third month, clamped to the end of the month when the day does not exist.
+///
+/// 中文: 财年可以从任意一天开始的财务日历。每个财务季度从每隔三个月的同一天开始，该天不存在时取当月的最后一天
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalCalendar {
+    start_month: u32,
+    start_day: u32,
+    naming: FiscalYearNaming,
+}
+
+/// English: A fiscal year or a fiscal quarter, with its first and last day.
+///
+/// 中文: 一个财年或财务季度，包含其第一天和最后一天
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalPeriod {
+    pub fiscal_year: i32,
+    /// English: `None` when the period is a whole fiscal year
+    ///
+    /// 中文: 该时期为整个财年时为`None`
+    pub quarter: Option<Quarter>,
+    pub first_day: NaiveDate,
+    pub last_day: NaiveDate,
+}
+
+impl FiscalCalendar {
+    /// English: The US federal government, from October 1st, named after the end year.
+    ///
+    /// 中文: 美国联邦政府，从10月1日开始，以结束年份命名
+    pub const US_FEDERAL: FiscalCalendar = FiscalCalendar {
+        start_month: 10,
+        start_day: 1,
+        naming: FiscalYearNaming::EndYear,
+    };
+    /// English: The UK tax year, from April 6th, named after the start year.
+    ///
+    /// 中文: 英国税务年度，从4月6日开始，以开始年份命名
+    pub const UK_TAX: FiscalCalendar = FiscalCalendar {
+        start_month: 4,
+        start_day: 6,
+        naming: FiscalYearNaming::StartYear,
+    };
+    /// English: Japan, from April 1st, named after the start year.
+    ///
+    /// 中文: 日本，从4月1日开始，以开始年份命名
+    pub const JAPAN: FiscalCalendar = FiscalCalendar {
+        start_month: 4,
+        start_day: 1,
+        naming: FiscalYearNaming::StartYear,
+    };
+
+    /// English: Create a fiscal calendar, panic when the start day does not exist in a non-leap year.
+    ///
+    /// 中文: 创建财务日历，开始日期在平年中不存在时panic
+    pub fn new(start_month: u32, start_day: u32, naming: FiscalYearNaming) -> Self {
+        Self::try_new(start_month, start_day, naming).unwrap()
+    }
+
+    /// English: Create a fiscal calendar, return an error when the start day does not exist in a non-leap year.
+    ///
+    /// 中文: 创建财务日历，开始日期在平年中不存在时返回错误
+    pub fn try_new(
+        start_month: u32,
+        start_day: u32,
+        naming: FiscalYearNaming,
+    ) -> Result<Self, DateUtilsError> {
+        if !(1..=12).contains(&start_month)
+            || start_day == 0
+            || start_day > days_in_month(2001, start_month)
+        {
+            return Err(DateUtilsError::InvalidDate);
+        }
+        Ok(FiscalCalendar {
+            start_month,
+            start_day,
+            naming,
+        })
+    }
+
+    /// English: Get the fiscal year of the date
+    ///
+    /// 中文: 获取日期所在的财年
+    pub fn fiscal_year(&self, date: &NaiveDate) -> i32 {
+        self.name(self.quarter_index(date).div_euclid(4) as i32)
+    }
+
+    /// English: Get the fiscal quarter of the date
+    ///
+    /// 中文: 获取日期所在的财务季度
+    pub fn fiscal_quarter(&self, date: &NaiveDate) -> Quarter {
+        quarter_of(self.quarter_index(date))
+    }
+
+    /// English: Get the first day of the given fiscal year
+    ///
+    /// 中文: 获取指定财年的第一天
+    pub fn fiscal_year_start(&self, fiscal_year: i32) -> NaiveDate {
+        let year = match self.naming {
+            FiscalYearNaming::EndYear if !self.starts_on_new_year() => fiscal_year - 1,
+            _ => fiscal_year,
+        };
+        self.quarter_start(year as i64 * 4)
+    }
+
+    /// English: Get the first day of the fiscal year of the date
+    ///
+    /// 中文: 获取日期所在财年的第一天
+    pub fn begin_of_fiscal_year(&self, date: &NaiveDate) -> NaiveDate {
+        self.quarter_start(self.quarter_index(date).div_euclid(4) * 4)
+    }
+
+    /// English: Get the last day of the fiscal year of the date
+    ///
+    /// 中文: 获取日期所在财年的最后一天
+    pub fn end_of_fiscal_year(&self, date: &NaiveDate) -> NaiveDate {
+        self.quarter_end(self.quarter_index(date).div_euclid(4) * 4 + 3)
+    }
+
+    /// English: Get the first day of the fiscal quarter of the date
+    ///
+    /// 中文: 获取日期所在财务季度的第一天
+    pub fn begin_of_fiscal_quarter(&self, date: &NaiveDate) -> NaiveDate {
+        self.quarter_start(self.quarter_index(date))
+    }
+
+    /// English: Get the last day of the fiscal quarter of the date
+    ///
+    /// 中文: 获取日期所在财务季度的最后一天
+    pub fn end_of_fiscal_quarter(&self, date: &NaiveDate) -> NaiveDate {
+        self.quarter_end(self.quarter_index(date))
+    }
+
+    /// English: Get the number of fiscal quarters from `other` to `one`, ignoring the days.
+    ///
+    /// 中文: 获取从`other`到`one`的财务季度数，不考虑具体的天数
+    pub fn diff_fiscal_quarters(&self, one: &NaiveDate, other: &NaiveDate) -> i64 {
+        self.quarter_index(one) - self.quarter_index(other)
+    }
+
+    /// English: Iterate over the fiscal quarters which overlap the dates from `start` to `end`
+    ///
+    /// 中文: 遍历与从`start`到`end`的日期有重叠的财务季度
+    pub fn quarters(&self, start: &NaiveDate, end: &NaiveDate) -> FiscalPeriods {
+        FiscalPeriods {
+            calendar: *self,
+            next: self.quarter_index(start),
+            last: self.quarter_index(end),
+            step: 1,
+        }
+    }
+
+    /// English: Iterate over the fiscal years which overlap the dates from `start` to `end`
+    ///
+    /// 中文: 遍历与从`start`到`end`的日期有重叠的财年
+    pub fn years(&self, start: &NaiveDate, end: &NaiveDate) -> FiscalPeriods {
+        FiscalPeriods {
+            calendar: *self,
+            next: self.quarter_index(start).div_euclid(4) * 4,
+            last: self.quarter_index(end),
+            step: 4,
+        }
+    }
+
+    fn starts_on_new_year(&self) -> bool {
+        self.start_month == 1 && self.start_day == 1
+    }
+
+    /// 按照命名方式，由财年开始时所在的日历年得到财年
+    fn name(&self, start_year: i32) -> i32 {
+        match self.naming {
+            FiscalYearNaming::EndYear if !self.starts_on_new_year() => start_year + 1,
+            _ => start_year,
+        }
+    }
+
+    /// 第`index`个财务季度的第一天，`index`为财年开始时的日历年乘以4再加上季度序号
+    fn quarter_start(&self, index: i64) -> NaiveDate {
+        let months = index * 3 + self.start_month as i64 - 1;
+        let year = months.div_euclid(12) as i32;
+        let month = months.rem_euclid(12) as u32 + 1;
+        let day = self.start_day.min(days_in_month(year, month));
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn quarter_end(&self, index: i64) -> NaiveDate {
+        self.quarter_start(index + 1).pred_opt().unwrap()
+    }
+
+    /// 日期所在的财务季度的序号
+    fn quarter_index(&self, date: &NaiveDate) -> i64 {
+        let months = date.year() as i64 * 12 + date.month0() as i64 - (self.start_month as i64 - 1);
+        let index = months.div_euclid(3);
+        if *date < self.quarter_start(index) {
+            index - 1
+        } else {
+            index
+        }
+    }
+
+    fn period(&self, index: i64, step: i64) -> FiscalPeriod {
+        FiscalPeriod {
+            fiscal_year: self.name(index.div_euclid(4) as i32),
+            quarter: (step == 1).then(|| quarter_of(index)),
+            first_day: self.quarter_start(index),
+            last_day: self.quarter_end(index + step - 1),
+        }
+    }
+}
+
+fn quarter_of(index: i64) -> Quarter {
+    match index.rem_euclid(4) {
+        0 => Quarter::Q1,
+        1 => Quarter::Q2,
+        2 => Quarter::Q3,
+        _ => Quarter::Q4,
+    }
+}
+
+/// English: The iterator of fiscal periods
+///
+/// 中文: 财务时期的迭代器
+#[derive(Debug, Clone)]
+pub struct FiscalPeriods {
+    calendar: FiscalCalendar,
+    next: i64,
+    last: i64,
+    step: i64,
+}
+
+impl Iterator for FiscalPeriods {
+    type Item = FiscalPeriod;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next > self.last {
+            return None;
+        }
+        let period = self.calendar.period(self.next, self.step);
+        self.next += self.step;
+        Some(period)
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+use crate::month::{MonthHelper, month_fraction};
+
+pub trait HalfYearHelper {
+    /// English: Get the half of the year
+    ///
+    /// 中文: 获取所在的半年
+    fn half(&self) -> Half;
+    /// English: Get the first day of the half year
+    ///
+    /// 中文: 获取半年的第一天
+    fn begin_of_half(&self) -> Self;
+    /// English: Get the last day of the half year
+    ///
+    /// 中文: 获取半年的最后一天
+    fn end_of_half(&self) -> Self;
+    /// English: Whether two dates are in the same half of the same year
+    ///
+    /// 中文: 两个日期是否在同一年的同一个半年
+    fn is_same_half(&self, other: &Self) -> bool;
+    /// English: Add half years
+    ///
+    /// 中文: 增加半年数
+    fn add_halves(&self, halves: i32) -> Self;
+    /// English: Add half years, return `None` when the result is out of range
+    ///
+    /// 中文: 增加半年数，结果超出范围时返回`None`
+    fn add_halves_opt(&self, halves: i32) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Subtract half years
+    ///
+    /// 中文: 减少半年数
+    fn sub_halves(&self, halves: i32) -> Self;
+    /// English: Subtract half years, return `None` when the result is out of range
+    ///
+    /// 中文: 减少半年数，结果超出范围时返回`None`
+    fn sub_halves_opt(&self, halves: i32) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Get the number of whole half years from `other` to `self`, which is negative when
+    /// `self` is before `other`
+    ///
+    /// 中文: 获取从`other`到`self`的完整半年数，`self`早于`other`时为负数
+    fn diff_halves(&self, other: &Self) -> i64;
+    /// English: Get the number of half years from `other` to `self`, ignoring the days
+    ///
+    /// 中文: 获取从`other`到`self`的半年数，不考虑具体的天数
+    fn diff_calendar_halves(&self, other: &Self) -> i64;
+}
+
+/// English: The first or second half of a year
+///
+/// 中文: 上半年或下半年
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Half {
+    /// English: The first half, from January to June
+    ///
+    /// 中文: 上半年，1月到6月
+    H1,
+    /// English: The second half, from July to December
+    ///
+    /// 中文: 下半年，7月到12月
+    H2,
+}
+
+impl Half {
+    /// English: Get the half of the month (1 to 12), return `None` when the month is invalid
+    ///
+    /// 中文: 获取月份(1到12)所在的半年，月份无效时返回`None`
+    pub fn from_month(month: u32) -> Option<Half> {
+        match month {
+            1..=6 => Some(Half::H1),
+            7..=12 => Some(Half::H2),
+            _ => None,
+        }
+    }
+
+    /// English: Get the number of the half, 1 or 2
+    ///
+    /// 中文: 获取半年的序号，为1或2
+    pub fn number(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// English: Get the first month of the half
+    ///
+    /// 中文: 获取半年的第一个月
+    pub fn first_month(&self) -> u32 {
+        *self as u32 * 6 + 1
+    }
+
+    /// English: Get the last month of the half
+    ///
+    /// 中文: 获取半年的最后一个月
+    pub fn last_month(&self) -> u32 {
+        self.first_month() + 5
+    }
+}
+
+impl Display for Half {
+    /// 默认输出`H1`的形式，使用`{:#}`时输出`上半年`的形式
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            let name = match self {
+                Half::H1 => "上半年",
+                Half::H2 => "下半年",
+            };
+            write!(f, "{}", name)
+        } else {
+            write!(f, "H{}", self.number())
+        }
+    }
+}
+
+/// 以公元0年上半年为0的半年序号
+fn half_index(date: &NaiveDate) -> i64 {
+    date.year() as i64 * 2 + date.month0() as i64 / 6
+}
+
+impl HalfYearHelper for NaiveDate {
+    fn half(&self) -> Half {
+        Half::from_month(self.month()).unwrap()
+    }
+
+    fn begin_of_half(&self) -> Self {
+        NaiveDate::from_ymd_opt(self.year(), self.half().first_month(), 1).unwrap()
+    }
+
+    fn end_of_half(&self) -> Self {
+        NaiveDate::from_ymd_opt(self.year(), self.half().last_month(), 1)
+            .unwrap()
+            .end_of_month()
+    }
+
+    fn is_same_half(&self, other: &Self) -> bool {
+        half_index(self) == half_index(other)
+    }
+
+    fn add_halves(&self, halves: i32) -> Self {
+        self.add_halves_opt(halves).unwrap()
+    }
+
+    fn add_halves_opt(&self, halves: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.add_months_opt(halves as i64 * 6)
+    }
+
+    fn sub_halves(&self, halves: i32) -> Self {
+        self.sub_halves_opt(halves).unwrap()
+    }
+
+    fn sub_halves_opt(&self, halves: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.sub_months_opt(halves as i64 * 6)
+    }
+
+    fn diff_halves(&self, other: &Self) -> i64 {
+        month_fraction(self, other).0 / 6
+    }
+
+    fn diff_calendar_halves(&self, other: &Self) -> i64 {
+        half_index(self) - half_index(other)
+    }
+}
+
+impl HalfYearHelper for NaiveDateTime {
+    fn half(&self) -> Half {
+        self.date().half()
+    }
+
+    fn begin_of_half(&self) -> Self {
+        self.date().begin_of_half().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    fn end_of_half(&self) -> Self {
+        self.date().end_of_half().and_hms_opt(23, 59, 59).unwrap()
+    }
+
+    fn is_same_half(&self, other: &Self) -> bool {
+        self.date().is_same_half(&other.date())
+    }
+
+    fn add_halves(&self, halves: i32) -> Self {
+        self.add_halves_opt(halves).unwrap()
+    }
+
+    fn add_halves_opt(&self, halves: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.add_months_opt(halves as i64 * 6)
+    }
+
+    fn sub_halves(&self, halves: i32) -> Self {
+        self.sub_halves_opt(halves).unwrap()
+    }
+
+    fn sub_halves_opt(&self, halves: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.sub_months_opt(halves as i64 * 6)
+    }
+
+    fn diff_halves(&self, other: &Self) -> i64 {
+        month_fraction(self, other).0 / 6
+    }
+
+    fn diff_calendar_halves(&self, other: &Self) -> i64 {
+        self.date().diff_calendar_halves(&other.date())
+    }
+}
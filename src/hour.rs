@@ -1,5 +1,8 @@
 use chrono::{Duration, NaiveDateTime, Timelike};
 
+use crate::error::DateUtilsError;
+use crate::rounding::{NANOS_PER_HOUR, Rounding, fraction, split_duration};
+
 /// English: The helper of hour
 ///
 /// 中文: 时助手
@@ -24,6 +27,16 @@ pub trait HourHelper {
     ///
     /// 中文：获取两个时间的小时差
     fn diff_hours(&self, other: &Self) -> i64;
+
+    /// English: Get the number of hours between the given dates with the given rounding method.
+    ///
+    /// 中文: 按照指定的取整方式计算两个日期之间的小时数
+    fn diff_hours_with(&self, other: &Self, rounding: Rounding) -> i64;
+
+    /// English: Get the number of hours between the given dates, including the fractional part.
+    ///
+    /// 中文: 计算两个日期之间的小时数，包含小数部分
+    fn diff_hours_f64(&self, other: &Self) -> f64;
     /// English: Get the end of one hour
     ///
     /// 中文: 获取小时的结束时间
@@ -42,6 +55,24 @@ pub trait HourHelper {
     fn sub_hours_opt(&self, hour: u32) -> Option<Self>
     where
         Self: Sized;
+    /// English: Add the specified number of hours, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 加上指定的小时数，溢出时返回错误而不是panic
+    fn try_add_hours(&self, hour: u32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.add_hours_opt(hour).ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Subtract the specified number of hours, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 减去给定的小时数，溢出时返回错误而不是panic
+    fn try_sub_hours(&self, hour: u32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.sub_hours_opt(hour).ok_or(DateUtilsError::OutOfRange)
+    }
 }
 
 impl HourHelper for NaiveDateTime {
@@ -62,6 +93,16 @@ impl HourHelper for NaiveDateTime {
         self.signed_duration_since(other.to_owned()).num_hours()
     }
 
+    fn diff_hours_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_HOUR);
+        rounding.apply(whole, remainder, NANOS_PER_HOUR)
+    }
+
+    fn diff_hours_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_HOUR);
+        fraction(whole, remainder, NANOS_PER_HOUR)
+    }
+
     fn end_of_hour(&self) -> Self {
         let hour = self.hour();
         self.date().and_hms_opt(hour, 59, 59).unwrap()
@@ -0,0 +1,241 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+use crate::error::DateUtilsError;
+
+/// English: The ISO 8601 week-date helper. The ISO week-year starts with the week which contains
+/// the first Thursday of the year, and every week starts on Monday.
+///
+/// 中文: ISO 8601周日期辅助函数。ISO周年从包含该年第一个星期四的那一周开始，每周从星期一开始
+pub trait IsoWeekHelper {
+    /// English: Get the ISO week-year, which may differ from the calendar year near the new year
+    ///
+    /// 中文: 获取ISO周年，在新年前后可能与日历年不同
+    fn iso_week_year(&self) -> i32;
+    /// English: Get the ISO week number, from 1 to 53. Call it as `IsoWeekHelper::iso_week(&date)`
+    /// when `chrono::Datelike` is also in scope.
+    ///
+    /// 中文: 获取ISO周数，取值为1到53。同时引入`chrono::Datelike`时，需要使用`IsoWeekHelper::iso_week(&date)`调用
+    fn iso_week(&self) -> u32;
+    /// English: Get the first day (Monday of week 1) of the ISO week-year
+    ///
+    /// 中文: 获取ISO周年的第一天（第1周的星期一）
+    fn begin_of_iso_week_year(&self) -> Self;
+    /// English: Get the end (Sunday of the last week) of the ISO week-year
+    ///
+    /// 中文: 获取ISO周年的结束（最后一周的星期日）
+    fn end_of_iso_week_year(&self) -> Self;
+    /// English: Get the number of weeks in the ISO week-year, 52 or 53
+    ///
+    /// 中文: 获取ISO周年的周数，为52或53
+    fn iso_weeks_in_year(&self) -> u32;
+    /// English: Add ISO week-years and keep the week number and the weekday. Week 53 becomes
+    /// week 52 when the target week-year has only 52 weeks.
+    ///
+    /// 中文: 增加ISO周年，并保持周数和星期几不变。目标周年只有52周时，第53周会变为第52周
+    fn add_iso_week_years(&self, n: i32) -> Self;
+    /// English: Add ISO week-years, return `None` when the result is out of range
+    ///
+    /// 中文: 增加ISO周年，结果超出范围时返回`None`
+    fn add_iso_week_years_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Format as an ISO week date, such as `2024-W05-3`
+    ///
+    /// 中文: 格式化为ISO周日期，如`2024-W05-3`
+    fn format_iso_week_date(&self) -> String;
+    /// English: Format as an ISO ordinal date, such as `2024-035`
+    ///
+    /// 中文: 格式化为ISO序数日期，如`2024-035`
+    fn format_ordinal_date(&self) -> String;
+    /// English: Parse an ISO week date, such as `2024-W05-3`
+    ///
+    /// 中文: 解析ISO周日期，如`2024-W05-3`
+    fn parse_iso_week_date(input: &str) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
+    /// English: Parse an ISO ordinal date, such as `2024-035`
+    ///
+    /// 中文: 解析ISO序数日期，如`2024-035`
+    fn parse_ordinal_date(input: &str) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
+}
+
+fn weeks_in_iso_year(year: i32) -> u32 {
+    if NaiveDate::from_isoywd_opt(year, 53, Weekday::Mon).is_some() {
+        53
+    } else {
+        52
+    }
+}
+
+/// 解析`2024-W05-3`，周数和星期几必须是两位和一位数字
+fn parse_week_date(input: &str) -> Option<NaiveDate> {
+    let (year, rest) = input.rsplit_once("-W")?;
+    let (week, weekday) = rest.split_once('-')?;
+    if week.len() != 2 || weekday.len() != 1 {
+        return None;
+    }
+    let weekday = match weekday.parse::<u8>().ok()? {
+        1 => Weekday::Mon,
+        2 => Weekday::Tue,
+        3 => Weekday::Wed,
+        4 => Weekday::Thu,
+        5 => Weekday::Fri,
+        6 => Weekday::Sat,
+        7 => Weekday::Sun,
+        _ => return None,
+    };
+    NaiveDate::from_isoywd_opt(year.parse().ok()?, week.parse().ok()?, weekday)
+}
+
+/// 解析`2024-035`，序数必须是三位数字
+fn parse_ordinal(input: &str) -> Option<NaiveDate> {
+    let (year, ordinal) = input.rsplit_once('-')?;
+    if ordinal.len() != 3 {
+        return None;
+    }
+    NaiveDate::from_yo_opt(year.parse().ok()?, ordinal.parse().ok()?)
+}
+
+/// 将日期时间拆分为日期部分和可选的`T`之后的时间部分
+fn parse_with_time(
+    input: &str,
+    parse_date: fn(&str) -> Option<NaiveDate>,
+) -> Result<NaiveDateTime, DateUtilsError> {
+    let error = || DateUtilsError::Parse(input.to_string());
+    let (date, time) = match input.split_once('T') {
+        Some((date, time)) => (
+            date,
+            NaiveTime::parse_from_str(time, "%H:%M:%S%.f").map_err(|_| error())?,
+        ),
+        None => (input, NaiveTime::MIN),
+    };
+    let date = parse_date(date).ok_or_else(error)?;
+    Ok(date.and_time(time))
+}
+
+impl IsoWeekHelper for NaiveDate {
+    fn iso_week_year(&self) -> i32 {
+        Datelike::iso_week(self).year()
+    }
+
+    fn iso_week(&self) -> u32 {
+        Datelike::iso_week(self).week()
+    }
+
+    fn begin_of_iso_week_year(&self) -> Self {
+        Self::from_isoywd_opt(self.iso_week_year(), 1, Weekday::Mon).unwrap()
+    }
+
+    fn end_of_iso_week_year(&self) -> Self {
+        let year = self.iso_week_year();
+        Self::from_isoywd_opt(year, weeks_in_iso_year(year), Weekday::Sun).unwrap()
+    }
+
+    fn iso_weeks_in_year(&self) -> u32 {
+        weeks_in_iso_year(self.iso_week_year())
+    }
+
+    fn add_iso_week_years(&self, n: i32) -> Self {
+        self.add_iso_week_years_opt(n).unwrap()
+    }
+
+    fn add_iso_week_years_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let year = self.iso_week_year().checked_add(n)?;
+        let week = IsoWeekHelper::iso_week(self).min(weeks_in_iso_year(year));
+        Self::from_isoywd_opt(year, week, self.weekday())
+    }
+
+    fn format_iso_week_date(&self) -> String {
+        self.format("%G-W%V-%u").to_string()
+    }
+
+    fn format_ordinal_date(&self) -> String {
+        self.format("%Y-%j").to_string()
+    }
+
+    fn parse_iso_week_date(input: &str) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        parse_week_date(input).ok_or_else(|| DateUtilsError::Parse(input.to_string()))
+    }
+
+    fn parse_ordinal_date(input: &str) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        parse_ordinal(input).ok_or_else(|| DateUtilsError::Parse(input.to_string()))
+    }
+}
+
+impl IsoWeekHelper for NaiveDateTime {
+    fn iso_week_year(&self) -> i32 {
+        self.date().iso_week_year()
+    }
+
+    fn iso_week(&self) -> u32 {
+        IsoWeekHelper::iso_week(&self.date())
+    }
+
+    fn begin_of_iso_week_year(&self) -> Self {
+        self.date()
+            .begin_of_iso_week_year()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn end_of_iso_week_year(&self) -> Self {
+        self.date()
+            .end_of_iso_week_year()
+            .and_hms_opt(23, 59, 59)
+            .unwrap()
+    }
+
+    fn iso_weeks_in_year(&self) -> u32 {
+        self.date().iso_weeks_in_year()
+    }
+
+    fn add_iso_week_years(&self, n: i32) -> Self {
+        self.add_iso_week_years_opt(n).unwrap()
+    }
+
+    fn add_iso_week_years_opt(&self, n: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.date()
+            .add_iso_week_years_opt(n)
+            .map(|date| date.and_time(self.time()))
+    }
+
+    /// 带有时间部分，如`2024-W05-3T08:30:00`
+    fn format_iso_week_date(&self) -> String {
+        self.format("%G-W%V-%uT%H:%M:%S").to_string()
+    }
+
+    /// 带有时间部分，如`2024-035T08:30:00`
+    fn format_ordinal_date(&self) -> String {
+        self.format("%Y-%jT%H:%M:%S").to_string()
+    }
+
+    /// 时间部分可以省略，省略时为零点
+    fn parse_iso_week_date(input: &str) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        parse_with_time(input, parse_week_date)
+    }
+
+    /// 时间部分可以省略，省略时为零点
+    fn parse_ordinal_date(input: &str) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        parse_with_time(input, parse_ordinal)
+    }
+}
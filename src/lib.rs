@@ -4,48 +4,91 @@
 mod common;
 mod day;
 mod decade;
+mod dst;
+mod duration;
+mod error;
+mod fiscal;
+mod half_year;
 mod hour;
+mod iso_week;
 mod millisecond;
 mod minute;
 mod month;
 mod now;
+mod overflow;
 mod period;
+mod planner;
 mod quarter;
 mod range;
+mod retail;
+mod rounding;
 mod second;
 #[cfg(test)]
 mod test;
+mod time_of_day;
+mod tz;
 mod utils;
 mod week;
+mod week_convention;
 mod year;
 
 #[cfg(feature = "common")]
 pub use common::{is_exist, CommonHelper};
 #[cfg(feature = "day")]
-pub use day::{DayHelper, DayTimeHelper, TodayHelper, TomorrowHelper, YesterdayHelper};
+pub use day::{
+    DayHelper, DayTimeHelper, TodayHelper, TomorrowHelper, YesterdayHelper, ZonedDayHelper,
+};
 #[cfg(feature = "decade")]
-pub use decade::DecadeHelper;
+pub use decade::{CenturyConvention, CenturyHelper, DecadeHelper, MillenniumHelper};
+pub use dst::{AmbiguityPolicy, GapPolicy};
+#[cfg(feature = "duration")]
+pub use duration::{DurationFormat, DurationStyle, format_duration, parse_duration};
+pub use error::DateUtilsError;
+#[cfg(feature = "fiscal")]
+pub use fiscal::{FiscalCalendar, FiscalPeriod, FiscalPeriods, FiscalYearNaming};
+#[cfg(feature = "half_year")]
+pub use half_year::{Half, HalfYearHelper};
 #[cfg(feature = "hour")]
 pub use hour::HourHelper;
+#[cfg(feature = "week")]
+pub use iso_week::IsoWeekHelper;
 #[cfg(feature = "millisecond")]
 pub use millisecond::MillisecondHelper;
 #[cfg(feature = "minute")]
 pub use minute::MinuteHelper;
 #[cfg(feature = "month")]
-pub use month::{MonthHelper, Range};
+pub use month::{MonthHelper, Range, YearMonth};
 #[cfg(feature = "now")]
-pub use now::Now;
+pub use now::{LocalZone, Now, ZoneNum, ZoneType};
+pub use overflow::OverflowPolicy;
 #[cfg(feature = "period")]
-pub use period::Period;
+pub use period::{
+    Breakdown, IsoDuration, IsoInterval, IsoOccurrences, IsoRepeatingInterval, Period, Units,
+    breakdown, breakdown_tz,
+};
+#[cfg(feature = "planner")]
+pub use planner::{WorkingHours, common_working_windows, convert_to_zones};
 #[cfg(feature = "quarter")]
-pub use quarter::{Quarter, QuarterHelper};
+pub use quarter::{Quarter, QuarterHelper, YearQuarter, YearQuarters};
 #[cfg(feature = "range")]
 pub use range::{DateRange, TimeRange};
+#[cfg(feature = "retail")]
+pub use retail::{LeapWeekPlacement, RetailCalendar, RetailPattern, YearEndRule};
+pub use rounding::{CalendarUnit, Rounding, RoundingHelper};
 #[cfg(feature = "second")]
 pub use second::SecondHelper;
+#[cfg(feature = "time")]
+pub use time_of_day::{DayPart, DayPartBoundaries, TimeOfDayHelper, TimeOfDayRange};
+#[cfg(feature = "tz")]
+pub use tz::{
+    PosixOffset, PosixTz, Transition, ZoneInfo, ZoneInfoOffset, is_ambiguous_local,
+    is_skipped_local, transitions_between,
+};
 #[cfg(feature = "utils")]
 pub use utils::{hour2day, minute2day, minute2hour, second2day, second2hour, second2minute};
 #[cfg(feature = "week")]
-pub use week::WeekHelper;
+pub use week::{WeekHelper, YearWeek};
+#[cfg(feature = "week")]
+pub use week_convention::WeekConvention;
 #[cfg(feature = "year")]
-pub use year::YearHelper;
+pub use year::{Year, YearHelper};
@@ -1,5 +1,7 @@
 use chrono::{Duration, NaiveDateTime, Timelike};
 
+use crate::error::DateUtilsError;
+
 pub trait MillisecondHelper {
     /// English: Add the specified number of milliseconds to the given date.
     ///
@@ -21,6 +23,26 @@ pub trait MillisecondHelper {
     fn sub_millisecond_opt(&self, millisecond: i32) -> Option<Self>
     where
         Self: Sized;
+    /// English: Add the specified number of milliseconds to the given date, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 给指定的日期添加指定的毫秒数，溢出时返回错误而不是panic
+    fn try_add_millisecond(&self, millisecond: i32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.add_millisecond_opt(millisecond)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Subtract the specified number of milliseconds from the given date, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 给指定的日期减去指定的毫秒数，溢出时返回错误而不是panic
+    fn try_sub_millisecond(&self, millisecond: i32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.sub_millisecond_opt(millisecond)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
     /// English: Get the number of milliseconds between the given dates.
     ///
     /// 中文: 获取两个日期之间的毫秒数。
@@ -33,6 +55,12 @@ pub trait MillisecondHelper {
     ///
     /// 中文: 设置指定日期的毫秒数。
     fn set_millisecond(&self, millisecond: i64) -> Self;
+    /// English: Set the milliseconds to the given date, returning an error when the milliseconds are not in `0..1000`.
+    ///
+    /// 中文: 设置指定日期的毫秒数，毫秒数不在`0..1000`之间时返回错误。
+    fn try_set_millisecond(&self, millisecond: i64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
 }
 
 impl MillisecondHelper for NaiveDateTime {
@@ -70,4 +98,12 @@ impl MillisecondHelper for NaiveDateTime {
         self.with_nanosecond(millisecond as u32 * 1_000_000)
             .unwrap()
     }
+
+    fn try_set_millisecond(&self, millisecond: i64) -> Result<Self, DateUtilsError> {
+        if !(0..1000).contains(&millisecond) {
+            return Err(DateUtilsError::OutOfRange);
+        }
+        self.with_nanosecond(millisecond as u32 * 1_000_000)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
 }
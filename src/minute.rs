@@ -1,4 +1,7 @@
 use chrono::{Duration, NaiveDateTime, Timelike};
+
+use crate::error::DateUtilsError;
+use crate::rounding::{NANOS_PER_MINUTE, Rounding, fraction, split_duration};
 /// English: The helper of minute
 ///
 /// 中文: 分钟助手
@@ -22,6 +25,14 @@ pub trait MinuteHelper {
     ///
     /// 中文：获取给定时间之间的分钟差
     fn diff_minutes(&self, other: &Self) -> i64;
+    /// English: Get the number of minutes between the given dates with the given rounding method.
+    ///
+    /// 中文: 按照指定的取整方式计算两个日期之间的分钟数
+    fn diff_minutes_with(&self, other: &Self, rounding: Rounding) -> i64;
+    /// English: Get the number of minutes between the given dates, including the fractional part.
+    ///
+    /// 中文: 计算两个日期之间的分钟数，包含小数部分
+    fn diff_minutes_f64(&self, other: &Self) -> f64;
     /// English: Get the end of one minute
     ///
     /// 中文: 获取分钟的结束时间
@@ -40,6 +51,26 @@ pub trait MinuteHelper {
     fn sub_minutes_opt(&self, minute: u32) -> Option<Self>
     where
         Self: Sized;
+    /// English: Add the specified number of minutes, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 加上指定的分钟数，溢出时返回错误而不是panic
+    fn try_add_minutes(&self, minute: u32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.add_minutes_opt(minute)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Subtract the specified number of minutes, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 减去给定的分钟数，溢出时返回错误而不是panic
+    fn try_sub_minutes(&self, minute: u32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.sub_minutes_opt(minute)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
 }
 
 impl MinuteHelper for NaiveDateTime {
@@ -62,6 +93,16 @@ impl MinuteHelper for NaiveDateTime {
         self.signed_duration_since(other.to_owned()).num_minutes()
     }
 
+    fn diff_minutes_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_MINUTE);
+        rounding.apply(whole, remainder, NANOS_PER_MINUTE)
+    }
+
+    fn diff_minutes_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_MINUTE);
+        fraction(whole, remainder, NANOS_PER_MINUTE)
+    }
+
     fn end_of_minute(&self) -> Self {
         self.with_second(59).unwrap()
     }
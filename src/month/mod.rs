@@ -1,10 +1,18 @@
+use crate::error::DateUtilsError;
+use crate::overflow::{OverflowPolicy, shift_months};
+use crate::rounding::{Rounding, fraction, nanos};
 use crate::week::WeekHelper;
 use crate::{
     day::DayHelper,
     utils::{MonthType, month_type},
 };
-use chrono::{Datelike, Months, NaiveDate, NaiveDateTime};
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime};
 use std::cmp::Ordering;
+use std::ops::Sub;
+
+mod year_month;
+
+pub use year_month::YearMonth;
 
 /// English: The helper of month
 ///
@@ -37,6 +45,16 @@ pub trait MonthHelper {
     ///
     /// 中文：获取两个日期之间的整月
     fn diff_months(&self, other: &Self) -> i64;
+    /// English: Get the number of full months between the given dates with the given rounding method.
+    /// The result is negative when the date is before the other one.
+    ///
+    /// 中文: 按照指定的取整方式计算两个日期之间的整月数，当前日期早于另一个日期时结果为负数
+    fn diff_months_with(&self, other: &Self, rounding: Rounding) -> i64;
+    /// English: Get the number of months between the given dates, including the fractional part computed against the length of the next month.
+    /// The result is negative when the date is before the other one.
+    ///
+    /// 中文: 计算两个日期之间的月数，包含按下一个月的长度计算的小数部分，当前日期早于另一个日期时结果为负数
+    fn diff_months_f64(&self, other: &Self) -> f64;
     /// English: Get the number of calendar months between the given dates.
     ///
     /// 中文: 获取日历上两个日期相差多少个月
@@ -73,6 +91,36 @@ pub trait MonthHelper {
     ///
     /// 中文: 减去指定月份
     fn sub_months_opt(&self, month: i64) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Add the specified number of months, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 加上指定月份，溢出时返回错误而不是panic
+    fn try_add_months(&self, month: i64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.add_months_opt(month).ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Subtract the specified number of months from the given date, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 减去指定月份，溢出时返回错误而不是panic
+    fn try_sub_months(&self, month: i64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.sub_months_opt(month).ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Add the specified number of months with the given overflow policy.
+    ///
+    /// 中文: 按照指定的溢出策略加上指定月份
+    fn add_months_with(&self, month: i64, policy: OverflowPolicy) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
+    /// English: Subtract the specified number of months with the given overflow policy.
+    ///
+    /// 中文: 按照指定的溢出策略减去指定月份
+    fn sub_months_with(&self, month: i64, policy: OverflowPolicy) -> Result<Self, DateUtilsError>
     where
         Self: Sized;
     /// English: Return the last day of a month for the given date. The result will be in the local timezone.
@@ -166,6 +214,16 @@ impl MonthHelper for NaiveDate {
         }
     }
 
+    fn diff_months_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder, unit) = month_fraction(self, other);
+        rounding.apply(whole, remainder, unit)
+    }
+
+    fn diff_months_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder, unit) = month_fraction(self, other);
+        fraction(whole, remainder, unit)
+    }
+
     fn diff_calendar_months(&self, other: &Self) -> i64 {
         if self > other {
             between_months(self, other)
@@ -213,6 +271,15 @@ impl MonthHelper for NaiveDate {
         }
     }
 
+    fn add_months_with(&self, month: i64, policy: OverflowPolicy) -> Result<Self, DateUtilsError> {
+        shift_months(self, month, policy)
+    }
+
+    fn sub_months_with(&self, month: i64, policy: OverflowPolicy) -> Result<Self, DateUtilsError> {
+        let month = month.checked_neg().ok_or(DateUtilsError::OutOfRange)?;
+        self.add_months_with(month, policy)
+    }
+
     fn last_day_of_month(&self) -> Self {
         self.with_day(self.days_in_month()).unwrap()
     }
@@ -272,10 +339,31 @@ where
     }
 }
 
+/// 计算两个日期之间的整月数，以及剩余的部分和下一个月的长度
+///
+/// 第一个日期早于第二个日期时，整月数和剩余的部分都为负数
+pub(crate) fn month_fraction<T>(one: &T, other: &T) -> (i64, i128, i128)
+where
+    T: MonthHelper + Ord + Copy + Sub<Output = Duration>,
+{
+    let whole = one.diff_months(other);
+    let (early, late) = if one > other {
+        (*other, *one)
+    } else {
+        (*one, *other)
+    };
+    let anchor = early.add_months(whole);
+    let next = early.add_months(whole + 1);
+    let (remainder, unit) = (nanos(late - anchor), nanos(next - anchor));
+    if one < other {
+        (-whole, -remainder, unit)
+    } else {
+        (whole, remainder, unit)
+    }
+}
+
 fn between_months(one: &NaiveDate, other: &NaiveDate) -> i64 {
-    let year_diff = one.year() - other.year();
-    let diff_month = one.month0() - other.month0();
-    year_diff as i64 * 12 + diff_month as i64
+    (one.year() as i64 - other.year() as i64) * 12 + one.month0() as i64 - other.month0() as i64
 }
 
 impl MonthHelper for NaiveDateTime {
@@ -320,6 +408,16 @@ impl MonthHelper for NaiveDateTime {
         }
     }
 
+    fn diff_months_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder, unit) = month_fraction(self, other);
+        rounding.apply(whole, remainder, unit)
+    }
+
+    fn diff_months_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder, unit) = month_fraction(self, other);
+        fraction(whole, remainder, unit)
+    }
+
     fn diff_calendar_months(&self, other: &Self) -> i64 {
         let one = self.date();
         let other = other.date();
@@ -365,6 +463,15 @@ impl MonthHelper for NaiveDateTime {
         }
     }
 
+    fn add_months_with(&self, month: i64, policy: OverflowPolicy) -> Result<Self, DateUtilsError> {
+        shift_months(&self.date(), month, policy).map(|date| date.and_time(self.time()))
+    }
+
+    fn sub_months_with(&self, month: i64, policy: OverflowPolicy) -> Result<Self, DateUtilsError> {
+        let month = month.checked_neg().ok_or(DateUtilsError::OutOfRange)?;
+        self.add_months_with(month, policy)
+    }
+
     fn last_day_of_month(&self) -> Self {
         self.date().end_of_month().and_hms_opt(0, 0, 0).unwrap()
     }
@@ -0,0 +1,184 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::DateUtilsError;
+use crate::month::MonthHelper;
+
+/// English: A month of a specific year, such as `2024-05`
+///
+/// 中文: 某一年中的某个月，如`2024-05`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    year: i32,
+    month: u32,
+}
+
+impl YearMonth {
+    /// English: Create a year month, panic when the month is invalid
+    ///
+    /// 中文: 创建某年的某个月，月份无效时panic
+    pub fn new(year: i32, month: u32) -> Self {
+        Self::try_new(year, month).unwrap()
+    }
+
+    /// English: Create a year month, return an error when the month is invalid or out of range
+    ///
+    /// 中文: 创建某年的某个月，月份无效或超出范围时返回错误
+    pub fn try_new(year: i32, month: u32) -> Result<Self, DateUtilsError> {
+        NaiveDate::from_ymd_opt(year, month, 1)
+            .map(YearMonth::from)
+            .ok_or(DateUtilsError::InvalidDate)
+    }
+
+    /// English: Get the year
+    ///
+    /// 中文: 获取年份
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// English: Get the month, from 1 to 12
+    ///
+    /// 中文: 获取月份，取值为1到12
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// English: Get the first day of the month
+    ///
+    /// 中文: 获取该月的第一天
+    pub fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1).unwrap()
+    }
+
+    /// English: Get the last day of the month
+    ///
+    /// 中文: 获取该月的最后一天
+    pub fn last_day(&self) -> NaiveDate {
+        self.first_day().end_of_month()
+    }
+
+    /// English: Whether the date is in the month
+    ///
+    /// 中文: 日期是否在该月中
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        YearMonth::from(*date) == *self
+    }
+
+    /// English: Iterate over the days of the month
+    ///
+    /// 中文: 遍历该月的每一天
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let last_day = self.last_day();
+        self.first_day()
+            .iter_days()
+            .take_while(move |date| *date <= last_day)
+    }
+
+    /// English: Get the next month
+    ///
+    /// 中文: 获取下一个月
+    pub fn succ(&self) -> YearMonth {
+        *self + 1
+    }
+
+    /// English: Get the previous month
+    ///
+    /// 中文: 获取上一个月
+    pub fn pred(&self) -> YearMonth {
+        *self - 1
+    }
+
+    /// English: Add months, return `None` when the result is out of range
+    ///
+    /// 中文: 增加月数，结果超出范围时返回`None`
+    pub fn checked_add(&self, months: i32) -> Option<YearMonth> {
+        YearMonth::from_index(self.index() + months as i64)
+    }
+
+    /// English: Subtract months, return `None` when the result is out of range
+    ///
+    /// 中文: 减少月数，结果超出范围时返回`None`
+    pub fn checked_sub(&self, months: i32) -> Option<YearMonth> {
+        YearMonth::from_index(self.index() - months as i64)
+    }
+
+    /// 以公元0年1月为0的月份序号
+    fn index(&self) -> i64 {
+        self.year as i64 * 12 + self.month as i64 - 1
+    }
+
+    fn from_index(index: i64) -> Option<YearMonth> {
+        let year = i32::try_from(index.div_euclid(12)).ok()?;
+        YearMonth::try_new(year, index.rem_euclid(12) as u32 + 1).ok()
+    }
+}
+
+impl From<NaiveDate> for YearMonth {
+    fn from(date: NaiveDate) -> Self {
+        let first_day = date.begin_of_month();
+        YearMonth {
+            year: first_day.year(),
+            month: first_day.month(),
+        }
+    }
+}
+
+impl Add<i32> for YearMonth {
+    type Output = YearMonth;
+
+    /// 结果超出范围时panic，使用`checked_add`可以避免panic
+    fn add(self, months: i32) -> Self::Output {
+        self.checked_add(months).unwrap()
+    }
+}
+
+impl Sub<i32> for YearMonth {
+    type Output = YearMonth;
+
+    /// 结果超出范围时panic，使用`checked_sub`可以避免panic
+    fn sub(self, months: i32) -> Self::Output {
+        self.checked_sub(months).unwrap()
+    }
+}
+
+impl Sub<YearMonth> for YearMonth {
+    type Output = i64;
+
+    /// 两个月份之间相差的月数
+    fn sub(self, other: YearMonth) -> Self::Output {
+        self.index() - other.index()
+    }
+}
+
+impl Display for YearMonth {
+    /// 默认输出`2024-05`的形式，使用`{:#}`时输出`2024年5月`的形式
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}年{}月", self.year, self.month)
+        } else {
+            write!(f, "{}-{:02}", self.year, self.month)
+        }
+    }
+}
+
+impl FromStr for YearMonth {
+    type Err = DateUtilsError;
+
+    /// 支持`2024-05`和`2024年5月`的形式
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || DateUtilsError::Parse(input.to_string());
+        let text = input.trim();
+        let (year, month) = match text.strip_suffix('月') {
+            Some(text) => text.split_once('年'),
+            None => text.rsplit_once('-').filter(|(_, month)| month.len() == 2),
+        }
+        .ok_or_else(error)?;
+        let year = year.parse::<i32>().map_err(|_| error())?;
+        let month = month.parse::<u32>().map_err(|_| error())?;
+        YearMonth::try_new(year, month).map_err(|_| error())
+    }
+}
@@ -1,27 +1,135 @@
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
+use std::str::FromStr;
 
-use chrono::{FixedOffset, NaiveDateTime};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, Offset, TimeZone};
 
+use crate::error::DateUtilsError;
 use crate::utils::utc_now;
 
 /// 当前时间
 pub struct Now;
 
-#[derive(Clone, Copy, Debug)]
-pub struct ZoneNum(i32);
+/// English: A time zone which gives the UTC offset at a UTC time, such as [`ZoneType`] or a
+/// time zone with daylight saving time.
+///
+/// 中文: 可以给出某个UTC时间的偏移量的时区，如[`ZoneType`]或有夏令时的时区
+pub trait LocalZone {
+    /// English: Get the offset at the UTC time
+    ///
+    /// 中文: 获取某个UTC时间的偏移量
+    fn offset_at(&self, utc: &NaiveDateTime) -> Result<FixedOffset, DateUtilsError>;
+}
+
+impl<Z: LocalZone + ?Sized> LocalZone for &Z {
+    fn offset_at(&self, utc: &NaiveDateTime) -> Result<FixedOffset, DateUtilsError> {
+        (**self).offset_at(utc)
+    }
+}
+
+impl LocalZone for ZoneType {
+    fn offset_at(&self, _utc: &NaiveDateTime) -> Result<FixedOffset, DateUtilsError> {
+        self.to_fixed_offset()
+    }
+}
+
+impl LocalZone for FixedOffset {
+    fn offset_at(&self, _utc: &NaiveDateTime) -> Result<FixedOffset, DateUtilsError> {
+        Ok(*self)
+    }
+}
+
+impl LocalZone for Local {
+    /// 使用系统时区，遵循环境变量`TZ`和`/etc/localtime`
+    fn offset_at(&self, utc: &NaiveDateTime) -> Result<FixedOffset, DateUtilsError> {
+        Ok(self.offset_from_utc_datetime(utc).fix())
+    }
+}
+
+/// English: A UTC offset with minute precision from -12:00 to 12:00, which is valid both in the
+/// east and in the west. Use [`ZoneType::east`] for offsets beyond +12:00 such as +14:00.
+///
+/// 中文: 精确到分钟的UTC偏移量，取值为-12:00到12:00，在东部时区和西部时区中都有效。
+/// 超出+12:00的偏移量（如+14:00）请使用[`ZoneType::east`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZoneNum {
+    hours: i32,
+    minutes: i32,
+}
+
+/// 东部时区的最大偏移分钟数，即+14:00
+const MAX_EAST_MINUTES: i32 = 14 * 60;
+/// 西部时区的最大偏移分钟数，即-12:00
+const MAX_WEST_MINUTES: i32 = 12 * 60;
+/// 在两个方向上都有效的最大偏移分钟数，即12:00
+const MAX_ZONE_NUM_MINUTES: i32 = 12 * 60;
 
 impl ZoneNum {
+    /// 以整小时创建时区数，取值为-12到12，负数表示相反的方向，超出范围时panic
     pub fn new(value: i32) -> Self {
-        assert!(value > -12 && value < 12);
-        Self(value)
+        Self::try_new(value).unwrap()
+    }
+
+    /// 以整小时创建时区数，超出-12到12的范围时返回错误而不是panic
+    pub fn try_new(value: i32) -> Result<Self, DateUtilsError> {
+        if (-12..=12).contains(&value) {
+            Ok(Self {
+                hours: value,
+                minutes: 0,
+            })
+        } else {
+            Err(DateUtilsError::InvalidOffset)
+        }
+    }
+
+    /// 以小时和分钟创建时区数，如印度的5小时30分，超出范围时panic
+    pub fn from_hm(hours: u32, minutes: u32) -> Self {
+        Self::try_from_hm(hours, minutes).unwrap()
+    }
+
+    /// 以小时和分钟创建时区数，分钟不小于60或超出12:00时返回错误
+    pub fn try_from_hm(hours: u32, minutes: u32) -> Result<Self, DateUtilsError> {
+        if minutes >= 60 || hours > 12 {
+            return Err(DateUtilsError::InvalidOffset);
+        }
+        let total = (hours * 60 + minutes) as i32;
+        if total > MAX_ZONE_NUM_MINUTES {
+            return Err(DateUtilsError::InvalidOffset);
+        }
+        Ok(Self::from_minutes(total))
+    }
+
+    /// 以非负的总分钟数创建时区数，范围由调用方检查
+    fn from_minutes(total: i32) -> Self {
+        Self {
+            hours: total / 60,
+            minutes: total % 60,
+        }
+    }
+
+    /// 小时部分
+    pub fn hours(&self) -> i32 {
+        self.hours
+    }
+
+    /// 分钟部分，取值为0到59
+    pub fn minutes(&self) -> i32 {
+        self.minutes
+    }
+
+    /// 总分钟数
+    pub fn total_minutes(&self) -> i32 {
+        self.hours * 60 + self.minutes
     }
 }
 
 impl Deref for ZoneNum {
     type Target = i32;
 
+    /// 小时部分，不包含分钟
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.hours
     }
 }
 
@@ -31,14 +139,157 @@ impl From<i32> for ZoneNum {
     }
 }
 
-/// 时区属性
+/// 时区属性，按偏移分钟数比较，如`East(-5)`与`West(5)`相等
+#[derive(Clone, Copy, Debug)]
 pub enum ZoneType {
-    /// 东部时区
+    /// 东部时区，最大为+14:00
     East(ZoneNum),
-    /// 西部时区
+    /// 西部时区，最大为-12:00
     West(ZoneNum),
 }
 
+impl ZoneType {
+    /// UTC时区
+    pub const UTC: ZoneType = ZoneType::East(ZoneNum {
+        hours: 0,
+        minutes: 0,
+    });
+
+    /// 以小时和分钟创建东部时区，超出+14:00时返回错误
+    pub fn east(hours: u32, minutes: u32) -> Result<Self, DateUtilsError> {
+        Self::try_from_minutes(Self::hm_to_minutes(hours, minutes)?)
+    }
+
+    /// 以小时和分钟创建西部时区，如纽芬兰的`west(3, 30)`，超出-12:00时返回错误
+    pub fn west(hours: u32, minutes: u32) -> Result<Self, DateUtilsError> {
+        Self::try_from_minutes(-Self::hm_to_minutes(hours, minutes)?)
+    }
+
+    /// 小时和分钟转换为总分钟数，分钟不小于60或小时超出14时返回错误
+    fn hm_to_minutes(hours: u32, minutes: u32) -> Result<i32, DateUtilsError> {
+        if minutes >= 60 || hours > 14 {
+            return Err(DateUtilsError::InvalidOffset);
+        }
+        Ok((hours * 60 + minutes) as i32)
+    }
+
+    /// 以带符号的分钟数创建时区，东部时区为正数，超出-12:00到+14:00的范围时返回错误
+    pub fn try_from_minutes(minutes: i32) -> Result<Self, DateUtilsError> {
+        if (-MAX_WEST_MINUTES..=MAX_EAST_MINUTES).contains(&minutes) {
+            if minutes < 0 {
+                Ok(ZoneType::West(ZoneNum::from_minutes(-minutes)))
+            } else {
+                Ok(ZoneType::East(ZoneNum::from_minutes(minutes)))
+            }
+        } else {
+            Err(DateUtilsError::InvalidOffset)
+        }
+    }
+
+    /// 带符号的偏移分钟数，东部时区为正数
+    pub fn offset_minutes(&self) -> i32 {
+        match self {
+            ZoneType::East(n) => n.total_minutes(),
+            ZoneType::West(n) => -n.total_minutes(),
+        }
+    }
+
+    /// 转换为`FixedOffset`，西部时区超出-12:00时返回错误
+    pub fn to_fixed_offset(&self) -> Result<FixedOffset, DateUtilsError> {
+        let minutes = Self::try_from_minutes(self.offset_minutes())?.offset_minutes();
+        FixedOffset::east_opt(minutes * 60).ok_or(DateUtilsError::InvalidOffset)
+    }
+}
+
+impl PartialEq for ZoneType {
+    /// 按带符号的偏移分钟数比较
+    fn eq(&self, other: &Self) -> bool {
+        self.offset_minutes() == other.offset_minutes()
+    }
+}
+
+impl Eq for ZoneType {}
+
+impl Hash for ZoneType {
+    /// 按带符号的偏移分钟数计算哈希，与`PartialEq`保持一致
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset_minutes().hash(state);
+    }
+}
+
+impl TryFrom<FixedOffset> for ZoneType {
+    type Error = DateUtilsError;
+
+    /// 偏移量必须是整分钟，并且在-12:00到+14:00的范围内
+    fn try_from(offset: FixedOffset) -> Result<Self, Self::Error> {
+        let seconds = offset.local_minus_utc();
+        if seconds % 60 != 0 {
+            return Err(DateUtilsError::InvalidOffset);
+        }
+        Self::try_from_minutes(seconds / 60)
+    }
+}
+
+impl TryFrom<ZoneType> for FixedOffset {
+    type Error = DateUtilsError;
+
+    fn try_from(zone: ZoneType) -> Result<Self, Self::Error> {
+        zone.to_fixed_offset()
+    }
+}
+
+impl Display for ZoneType {
+    /// 输出`+05:30`的形式
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let minutes = self.offset_minutes();
+        let sign = if minutes < 0 { '-' } else { '+' };
+        let minutes = minutes.abs();
+        write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+impl FromStr for ZoneType {
+    type Err = DateUtilsError;
+
+    /// 支持`Z`、`UTC`、`+05:30`、`+0530`、`+05`、`UTC+8`和`GMT-3`等形式
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || DateUtilsError::Parse(input.to_string());
+        let text = input.trim();
+        let text = ["UTC", "GMT"]
+            .iter()
+            .find_map(|prefix| text.strip_prefix(prefix))
+            .unwrap_or(text);
+        if text.is_empty() || text == "Z" {
+            return Ok(ZoneType::UTC);
+        }
+        let (negative, rest) = if let Some(rest) = text.strip_prefix('+') {
+            (false, rest)
+        } else if let Some(rest) = text.strip_prefix(['-', '\u{2212}']) {
+            (true, rest)
+        } else {
+            return Err(error());
+        };
+        let (hours, minutes) = match rest.split_once(':') {
+            Some((hours, minutes)) => (hours, minutes),
+            None if rest.len() == 4 && rest.is_ascii() => rest.split_at(2),
+            None => (rest, "0"),
+        };
+        // 只接受ASCII数字，避免`parse`再接受一次正负号
+        let is_digits =
+            |text: &str| (1..=2).contains(&text.len()) && text.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(hours) || !is_digits(minutes) {
+            return Err(error());
+        }
+        let hours = hours.parse::<i32>().map_err(|_| error())?;
+        let minutes = minutes.parse::<i32>().map_err(|_| error())?;
+        if minutes >= 60 {
+            return Err(error());
+        }
+        let total = hours * 60 + minutes;
+        Self::try_from_minutes(if negative { -total } else { total })
+    }
+}
+
 enum Timestamp {
     Micro,
     Nano,
@@ -56,29 +307,54 @@ impl Now {
     ///
     /// # 参数
     /// - n 所属时间
-    /// - zone 时区属性，东部时区/西部时区
+    /// - zone 时区属性，东部时区/西部时区，或者有夏令时的时区
     ///
-    pub fn local(zone: ZoneType) -> NaiveDateTime {
-        let an_hour = 60 * 60;
-        let offset = match zone {
-            ZoneType::East(n) => {
-                let offset_seconds = *n * an_hour;
-                FixedOffset::east_opt(offset_seconds).unwrap()
-            }
-            ZoneType::West(n) => {
-                let offset_seconds = *n * an_hour;
-                FixedOffset::west_opt(offset_seconds).unwrap()
-            }
-        };
-        utc_now().with_timezone(&offset).naive_local()
+    pub fn local(zone: impl LocalZone) -> NaiveDateTime {
+        Self::try_local(zone).unwrap()
+    }
+
+    /// 以当前时区显示当前时间，时区偏移无效时返回错误而不是panic
+    ///
+    /// # 参数
+    /// - zone 时区属性，东部时区/西部时区，或者有夏令时的时区
+    ///
+    pub fn try_local(zone: impl LocalZone) -> Result<NaiveDateTime, DateUtilsError> {
+        let now = utc_now();
+        let offset = zone.offset_at(&now.naive_utc())?;
+        Ok(now.with_timezone(&offset).naive_local())
+    }
+
+    /// 以系统时区显示当前时间，遵循环境变量`TZ`和`/etc/localtime`
+    pub fn system() -> NaiveDateTime {
+        Self::in_zone(&Local).naive_local()
+    }
+
+    /// 系统时区的当前日期，遵循环境变量`TZ`和`/etc/localtime`
+    pub fn system_date() -> NaiveDate {
+        Self::system().date()
+    }
+
+    /// 某个时区的当前时间
+    ///
+    /// # 参数
+    /// - zone 实现了`TimeZone`的时区，如`FixedOffset`或有夏令时的时区
+    ///
+    pub fn in_zone<Tz: TimeZone>(zone: &Tz) -> DateTime<Tz> {
+        utc_now().with_timezone(zone)
     }
 
     fn timestamp_utc(time_type: Timestamp) -> i64 {
+        Self::try_timestamp_utc(time_type).unwrap()
+    }
+
+    fn try_timestamp_utc(time_type: Timestamp) -> Result<i64, DateUtilsError> {
         match time_type {
-            Timestamp::Micro => utc_now().timestamp_micros(),
-            Timestamp::Nano => utc_now().timestamp_nanos_opt().unwrap(),
-            Timestamp::Milli => utc_now().timestamp_millis(),
-            Timestamp::Second => utc_now().timestamp(),
+            Timestamp::Micro => Ok(utc_now().timestamp_micros()),
+            Timestamp::Nano => utc_now()
+                .timestamp_nanos_opt()
+                .ok_or(DateUtilsError::OutOfRange),
+            Timestamp::Milli => Ok(utc_now().timestamp_millis()),
+            Timestamp::Second => Ok(utc_now().timestamp()),
         }
     }
 
@@ -92,6 +368,11 @@ impl Now {
         Self::timestamp_utc(Timestamp::Nano)
     }
 
+    /// 以纳秒为单位的时间戳表示当前时间，超出`i64`范围时返回错误而不是panic
+    pub fn try_timestamp_nanos() -> Result<i64, DateUtilsError> {
+        Self::try_timestamp_utc(Timestamp::Nano)
+    }
+
     /// 以微秒为单位的时间戳表示当前时间
     pub fn timestamp_micros() -> i64 {
         Self::timestamp_utc(Timestamp::Micro)
@@ -102,30 +383,41 @@ impl Now {
         Self::timestamp_utc(Timestamp::Milli)
     }
 
-    fn timestamp_with_local(zone_type: ZoneType, time_type: Timestamp) -> i64 {
-        let time = Self::local(zone_type);
+    fn timestamp_with_local(zone_type: impl LocalZone, time_type: Timestamp) -> i64 {
+        Self::try_timestamp_with_local(zone_type, time_type).unwrap()
+    }
+
+    fn try_timestamp_with_local(
+        zone_type: impl LocalZone,
+        time_type: Timestamp,
+    ) -> Result<i64, DateUtilsError> {
+        let time = Self::try_local(zone_type)?.and_utc();
         match time_type {
-            Timestamp::Micro => time.and_utc().timestamp_micros(),
-            Timestamp::Nano => time.and_utc().timestamp_nanos_opt().unwrap(),
-            Timestamp::Milli => time.and_utc().timestamp_millis(),
-            Timestamp::Second => time.and_utc().timestamp(),
+            Timestamp::Micro => Ok(time.timestamp_micros()),
+            Timestamp::Nano => time.timestamp_nanos_opt().ok_or(DateUtilsError::OutOfRange),
+            Timestamp::Milli => Ok(time.timestamp_millis()),
+            Timestamp::Second => Ok(time.timestamp()),
         }
     }
 
     /// 以时间戳的形式表示当地当前时间
-    pub fn timestamp_local(zone_type: ZoneType) -> i64 {
+    pub fn timestamp_local(zone_type: impl LocalZone) -> i64 {
         Self::timestamp_with_local(zone_type, Timestamp::Second)
     }
     /// 以纳秒为单位时间戳的形式表示当地当前时间
-    pub fn timestamp_local_nanos(zone_type: ZoneType) -> i64 {
+    pub fn timestamp_local_nanos(zone_type: impl LocalZone) -> i64 {
         Self::timestamp_with_local(zone_type, Timestamp::Nano)
     }
+    /// 以纳秒为单位时间戳的形式表示当地当前时间，超出`i64`范围时返回错误而不是panic
+    pub fn try_timestamp_local_nanos(zone_type: impl LocalZone) -> Result<i64, DateUtilsError> {
+        Self::try_timestamp_with_local(zone_type, Timestamp::Nano)
+    }
     /// 以毫秒为单位时间戳的形式表示当地当前时间
-    pub fn timestamp_local_milli(zone_type: ZoneType) -> i64 {
+    pub fn timestamp_local_milli(zone_type: impl LocalZone) -> i64 {
         Self::timestamp_with_local(zone_type, Timestamp::Milli)
     }
     /// 以微秒为单位时间戳的形式表示当地当前时间
-    pub fn timestamp_local_micro(zone_type: ZoneType) -> i64 {
+    pub fn timestamp_local_micro(zone_type: impl LocalZone) -> i64 {
         Self::timestamp_with_local(zone_type, Timestamp::Micro)
     }
 }
@@ -164,7 +456,7 @@ mod tests {
 
     #[test]
     fn test_now_local_east() {
-        let local = Now::local(ZoneType::East(ZoneNum(8)));
+        let local = Now::local(ZoneType::East(ZoneNum::new(8)));
         let datetime = gen_time(2000, 1, 1, 8, 0, 0).unwrap();
         assert_eq!(local, datetime);
     }
@@ -176,56 +468,208 @@ mod tests {
         assert_eq!(local, datetime);
     }
 
+    #[test]
+    fn test_now_in_zone() {
+        let zone = FixedOffset::east_opt(9 * 3600).unwrap();
+        let now = Now::in_zone(&zone);
+        assert_eq!(now.naive_local(), gen_time(2000, 1, 1, 9, 0, 0).unwrap());
+        assert_eq!(now.offset(), &zone);
+    }
+
+    #[test]
+    fn test_now_system() {
+        let offset = Local.offset_from_utc_datetime(&get_utc().unwrap()).fix();
+        let expected = get_utc().unwrap() + offset;
+        assert_eq!(Now::system(), expected);
+        assert_eq!(Now::system_date(), expected.date());
+        assert_eq!(Now::local(Local), expected);
+    }
+
+    #[test]
+    fn test_zone_num_try_new() {
+        assert!(ZoneNum::try_new(8).is_ok());
+        assert!(ZoneNum::try_new(12).is_ok());
+        assert_eq!(*ZoneNum::try_new(-5).unwrap(), -5);
+        assert_eq!(
+            ZoneNum::try_new(13).unwrap_err(),
+            DateUtilsError::InvalidOffset
+        );
+        assert_eq!(
+            ZoneNum::try_new(-13).unwrap_err(),
+            DateUtilsError::InvalidOffset
+        );
+        assert_eq!(
+            ZoneNum::try_from_hm(12, 30).unwrap_err(),
+            DateUtilsError::InvalidOffset
+        );
+        assert_eq!(
+            ZoneNum::try_from_hm(5, 60).unwrap_err(),
+            DateUtilsError::InvalidOffset
+        );
+        assert_eq!(ZoneNum::from_hm(5, 45).total_minutes(), 345);
+        assert_eq!(*ZoneNum::from_hm(5, 45), 5);
+    }
+
+    #[test]
+    fn test_now_local_negative_zone_num() {
+        let local = Now::local(ZoneType::East((-5).into()));
+        assert_eq!(local, gen_time(1999, 12, 31, 19, 0, 0).unwrap());
+        let local = Now::local(ZoneType::West((-5).into()));
+        assert_eq!(local, gen_time(2000, 1, 1, 5, 0, 0).unwrap());
+        assert_eq!(ZoneType::East((-5).into()).to_string(), "-05:00");
+    }
+
+    #[test]
+    fn test_zone_type_east_and_west() {
+        let kiribati = ZoneType::east(14, 0).unwrap();
+        assert_eq!(kiribati.offset_minutes(), 14 * 60);
+        let newfoundland = ZoneType::west(3, 30).unwrap();
+        assert_eq!(newfoundland, ZoneType::West(ZoneNum::from_hm(3, 30)));
+        assert_eq!(ZoneType::east(14, 1), Err(DateUtilsError::InvalidOffset));
+        assert_eq!(ZoneType::west(13, 0), Err(DateUtilsError::InvalidOffset));
+        assert_eq!(ZoneType::west(5, 60), Err(DateUtilsError::InvalidOffset));
+    }
+
+    #[test]
+    fn test_now_local_sub_hour() {
+        let local = Now::local(ZoneType::East(ZoneNum::from_hm(5, 30)));
+        assert_eq!(local, gen_time(2000, 1, 1, 5, 30, 0).unwrap());
+        let local = Now::local(ZoneType::West(ZoneNum::from_hm(3, 30)));
+        assert_eq!(local, gen_time(1999, 12, 31, 20, 30, 0).unwrap());
+        let local = Now::local(ZoneType::east(14, 0).unwrap());
+        assert_eq!(local, gen_time(2000, 1, 1, 14, 0, 0).unwrap());
+        let local = Now::local(ZoneType::West(12.into()));
+        assert_eq!(local, gen_time(1999, 12, 31, 12, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_now_local_posix_tz() {
+        let tz: crate::tz::PosixTz = "CST6CDT,M3.2.0,M11.1.0".parse().unwrap();
+        assert_eq!(Now::local(&tz), gen_time(1999, 12, 31, 18, 0, 0).unwrap());
+        let tz: crate::tz::PosixTz = "AEST-10AEDT,M10.1.0,M4.1.0/3".parse().unwrap();
+        assert_eq!(Now::local(tz), gen_time(2000, 1, 1, 11, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_zone_type_from_str() {
+        let india = ZoneType::East(ZoneNum::from_hm(5, 30));
+        assert_eq!("+05:30".parse::<ZoneType>(), Ok(india));
+        assert_eq!("+0530".parse::<ZoneType>(), Ok(india));
+        assert_eq!("UTC+8".parse::<ZoneType>(), Ok(ZoneType::East(8.into())));
+        assert_eq!("GMT-3".parse::<ZoneType>(), Ok(ZoneType::West(3.into())));
+        assert_eq!(
+            "\u{2212}03:30".parse::<ZoneType>(),
+            Ok(ZoneType::West(ZoneNum::from_hm(3, 30)))
+        );
+        assert_eq!("Z".parse::<ZoneType>(), Ok(ZoneType::UTC));
+        assert_eq!("UTC".parse::<ZoneType>(), Ok(ZoneType::UTC));
+        assert_eq!(
+            "-03:30".parse::<ZoneType>(),
+            Ok(ZoneType::West(ZoneNum::from_hm(3, 30)))
+        );
+        assert_eq!(
+            "+15:00".parse::<ZoneType>(),
+            Err(DateUtilsError::InvalidOffset)
+        );
+        assert!("+5:3x".parse::<ZoneType>().is_err());
+        assert!("+-5".parse::<ZoneType>().is_err());
+        assert!("UTC+-5".parse::<ZoneType>().is_err());
+        assert!("-+3:30".parse::<ZoneType>().is_err());
+        assert!("+5:-3".parse::<ZoneType>().is_err());
+        assert!("+5:".parse::<ZoneType>().is_err());
+        assert!("+1\u{e9}1".parse::<ZoneType>().is_err());
+        assert!("CET".parse::<ZoneType>().is_err());
+        assert_eq!(india.to_string(), "+05:30");
+        assert_eq!(ZoneType::West(ZoneNum::new(12)).to_string(), "-12:00");
+    }
+
+    #[test]
+    fn test_zone_type_eq_and_hash() {
+        use std::collections::HashSet;
+
+        let west = ZoneType::West(ZoneNum::new(5));
+        assert_eq!(ZoneType::East(ZoneNum::new(-5)), west);
+        assert_eq!(ZoneType::West(ZoneNum::new(0)), ZoneType::UTC);
+        assert_ne!(ZoneType::East(ZoneNum::new(5)), west);
+        let zones: HashSet<ZoneType> = [ZoneType::East(ZoneNum::new(-5)), west].into();
+        assert_eq!(zones.len(), 1);
+    }
+
+    #[test]
+    fn test_zone_type_fixed_offset() {
+        let nepal = ZoneType::East(ZoneNum::from_hm(5, 45));
+        let offset = FixedOffset::east_opt(345 * 60).unwrap();
+        assert_eq!(nepal.to_fixed_offset(), Ok(offset));
+        assert_eq!(ZoneType::try_from(offset), Ok(nepal));
+        assert_eq!(FixedOffset::try_from(nepal), Ok(offset));
+        let offset = FixedOffset::east_opt(-13 * 3600).unwrap();
+        assert_eq!(
+            ZoneType::try_from(offset),
+            Err(DateUtilsError::InvalidOffset)
+        );
+        let offset = FixedOffset::east_opt(30).unwrap();
+        assert_eq!(
+            ZoneType::try_from(offset),
+            Err(DateUtilsError::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn test_now_try_local() {
+        let local = Now::try_local(ZoneType::East(8.into()));
+        assert_eq!(local, Ok(gen_time(2000, 1, 1, 8, 0, 0).unwrap()));
+    }
+
     #[test]
     fn test_now_timestamp() {
         let result = Now::timestamp();
-        let t = get_utc().unwrap().timestamp();
+        let t = get_utc().unwrap().and_utc().timestamp();
         assert_eq!(result, t)
     }
 
     #[test]
     fn test_now_timestamp_micros() {
         let result = Now::timestamp_micros();
-        let t = get_utc().unwrap().timestamp_micros();
+        let t = get_utc().unwrap().and_utc().timestamp_micros();
         assert_eq!(result, t)
     }
 
     #[test]
     fn test_now_timestamp_nanos() {
         let result = Now::timestamp_nanos();
-        let t = get_utc().unwrap().timestamp_nanos_opt().unwrap();
+        let t = get_utc().unwrap().and_utc().timestamp_nanos_opt().unwrap();
         assert_eq!(result, t)
     }
     #[test]
     fn test_now_timestamp_millis() {
         let result = Now::timestamp_millis();
-        let t = get_utc().unwrap().timestamp_millis();
+        let t = get_utc().unwrap().and_utc().timestamp_millis();
         assert_eq!(result, t)
     }
 
     #[test]
     fn test_local_timestamp() {
         let result = Now::timestamp_local(ZoneType::East(8.into()));
-        let t = get_east().unwrap().timestamp();
+        let t = get_east().unwrap().and_utc().timestamp();
         assert_eq!(result, t)
     }
 
     #[test]
     fn test_local_timestamp_micros() {
         let result = Now::timestamp_local_micro(ZoneType::East(8.into()));
-        let t = get_east().unwrap().timestamp_micros();
+        let t = get_east().unwrap().and_utc().timestamp_micros();
         assert_eq!(result, t)
     }
     #[test]
     fn test_local_timestamp_millis() {
         let result = Now::timestamp_local_milli(ZoneType::East(8.into()));
-        let t = get_east().unwrap().timestamp_millis();
+        let t = get_east().unwrap().and_utc().timestamp_millis();
         assert_eq!(result, t)
     }
     #[test]
     fn test_local_timestamp_nanos() {
         let result = Now::timestamp_local_nanos(ZoneType::East(8.into()));
-        let t = get_east().unwrap().timestamp_nanos_opt().unwrap();
+        let t = get_east().unwrap().and_utc().timestamp_nanos_opt().unwrap();
         assert_eq!(result, t)
     }
 }
@@ -0,0 +1,117 @@
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::error::DateUtilsError;
+use crate::utils::days_in_month;
+
+/// English: The policy of adding months when the day does not exist in the target month.
+///
+/// 中文: 加减月份时，目标月份中不存在对应日期的处理策略
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// English: Clamp to the last day of the target month, e.g. Jan 31 + 1 month = Feb 28.
+    ///
+    /// 中文: 取目标月份的最后一天，如1月31日加1个月为2月28日
+    #[default]
+    Clamp,
+    /// English: Roll the extra days into the next month like JavaScript, e.g. Jan 31 + 1 month = Mar 3.
+    ///
+    /// 中文: 像JavaScript一样将多出的天数顺延到下个月，如1月31日加1个月为3月3日
+    Overflow,
+    /// English: The end of a month stays the end of a month, e.g. Feb 28 + 1 month = Mar 31.
+    ///
+    /// 中文: 月末仍然是月末，如2月28日加1个月为3月31日
+    StickyEndOfMonth,
+    /// English: Return an error when the day does not exist in the target month.
+    ///
+    /// 中文: 目标月份中不存在对应日期时返回错误
+    Error,
+}
+
+/// 按照给定的策略对日期加减月份
+pub(crate) fn shift_months(
+    date: &NaiveDate,
+    months: i64,
+    policy: OverflowPolicy,
+) -> Result<NaiveDate, DateUtilsError> {
+    let total = (date.year() as i64 * 12 + date.month0() as i64)
+        .checked_add(months)
+        .ok_or(DateUtilsError::OutOfRange)?;
+    let year = i32::try_from(total.div_euclid(12)).map_err(|_| DateUtilsError::OutOfRange)?;
+    let month = total.rem_euclid(12) as u32 + 1;
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1).ok_or(DateUtilsError::OutOfRange)?;
+    let day = date.day();
+    let last_day = days_in_month(year, month);
+    let result = match policy {
+        OverflowPolicy::Clamp => first_day.with_day(day.min(last_day)),
+        OverflowPolicy::Overflow => first_day.checked_add_days(Days::new(day as u64 - 1)),
+        OverflowPolicy::StickyEndOfMonth => {
+            if day == days_in_month(date.year(), date.month()) {
+                first_day.with_day(last_day)
+            } else {
+                first_day.with_day(day.min(last_day))
+            }
+        }
+        OverflowPolicy::Error => {
+            return first_day.with_day(day).ok_or(DateUtilsError::InvalidDate);
+        }
+    };
+    result.ok_or(DateUtilsError::OutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_shift_months_clamp() {
+        let date = get_date(2023, 1, 31);
+        let result = shift_months(&date, 1, OverflowPolicy::Clamp);
+        assert_eq!(result, Ok(get_date(2023, 2, 28)));
+        let result = shift_months(&date, -2, OverflowPolicy::Clamp);
+        assert_eq!(result, Ok(get_date(2022, 11, 30)));
+    }
+
+    #[test]
+    fn test_shift_months_overflow() {
+        let date = get_date(2023, 1, 31);
+        let result = shift_months(&date, 1, OverflowPolicy::Overflow);
+        assert_eq!(result, Ok(get_date(2023, 3, 3)));
+        let date = get_date(2024, 1, 31);
+        let result = shift_months(&date, 1, OverflowPolicy::Overflow);
+        assert_eq!(result, Ok(get_date(2024, 3, 2)));
+    }
+
+    #[test]
+    fn test_shift_months_sticky_end_of_month() {
+        let date = get_date(2023, 2, 28);
+        let result = shift_months(&date, 1, OverflowPolicy::StickyEndOfMonth);
+        assert_eq!(result, Ok(get_date(2023, 3, 31)));
+        let date = get_date(2023, 2, 27);
+        let result = shift_months(&date, 1, OverflowPolicy::StickyEndOfMonth);
+        assert_eq!(result, Ok(get_date(2023, 3, 27)));
+        let date = get_date(2023, 3, 31);
+        let result = shift_months(&date, -1, OverflowPolicy::StickyEndOfMonth);
+        assert_eq!(result, Ok(get_date(2023, 2, 28)));
+    }
+
+    #[test]
+    fn test_shift_months_error() {
+        let date = get_date(2023, 1, 31);
+        let result = shift_months(&date, 1, OverflowPolicy::Error);
+        assert_eq!(result, Err(DateUtilsError::InvalidDate));
+        let result = shift_months(&date, 2, OverflowPolicy::Error);
+        assert_eq!(result, Ok(get_date(2023, 3, 31)));
+    }
+
+    #[test]
+    fn test_shift_months_out_of_range() {
+        let result = shift_months(&NaiveDate::MAX, 12, OverflowPolicy::Clamp);
+        assert_eq!(result, Err(DateUtilsError::OutOfRange));
+        let result = shift_months(&NaiveDate::MIN, i64::MIN, OverflowPolicy::Clamp);
+        assert_eq!(result, Err(DateUtilsError::OutOfRange));
+    }
+}
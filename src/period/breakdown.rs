@@ -0,0 +1,402 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{BitOr, Neg, Sub};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, TimeZone};
+
+use crate::overflow::{OverflowPolicy, shift_months};
+use crate::rounding::{
+    NANOS_PER_HOUR, NANOS_PER_MILLISECOND, NANOS_PER_MINUTE, NANOS_PER_SECOND, nanos,
+};
+
+/// English: The units which a [`Breakdown`] is made up of. Units can be combined with `|`.
+///
+/// 中文: [`Breakdown`]包含的单位，可以使用`|`进行组合
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Units(u8);
+
+impl Units {
+    /// English: Years
+    ///
+    /// 中文: 年
+    pub const YEARS: Units = Units(1);
+    /// English: Months
+    ///
+    /// 中文: 月
+    pub const MONTHS: Units = Units(1 << 1);
+    /// English: Weeks
+    ///
+    /// 中文: 周
+    pub const WEEKS: Units = Units(1 << 2);
+    /// English: Days
+    ///
+    /// 中文: 天
+    pub const DAYS: Units = Units(1 << 3);
+    /// English: Hours
+    ///
+    /// 中文: 小时
+    pub const HOURS: Units = Units(1 << 4);
+    /// English: Minutes
+    ///
+    /// 中文: 分钟
+    pub const MINUTES: Units = Units(1 << 5);
+    /// English: Seconds
+    ///
+    /// 中文: 秒
+    pub const SECONDS: Units = Units(1 << 6);
+    /// English: Milliseconds
+    ///
+    /// 中文: 毫秒
+    pub const MILLISECONDS: Units = Units(1 << 7);
+    /// English: All of the units
+    ///
+    /// 中文: 所有的单位
+    pub const ALL: Units = Units(u8::MAX);
+
+    /// English: Whether all of the given units are included
+    ///
+    /// 中文: 是否包含给定的所有单位
+    pub const fn contains(self, other: Units) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for Units {
+    fn default() -> Self {
+        Units::ALL
+    }
+}
+
+impl BitOr for Units {
+    type Output = Units;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Units(self.0 | rhs.0)
+    }
+}
+
+impl Sub for Units {
+    type Output = Units;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Units(self.0 & !rhs.0)
+    }
+}
+
+/// English: The interval between two points in time, broken down into the selected units.
+/// The larger units borrow from the calendar, so the number of days in a month depends on the
+/// months which are crossed. Units which are not selected are carried to the smaller units, and
+/// the part smaller than the smallest selected unit is truncated.
+/// All of the fields are negative when the end is before the start.
+///
+/// 中文: 两个时间点之间的间隔，按照选定的单位进行拆分。较大的单位按照日历进行借位，所以一个月的天数取决于
+/// 所跨越的月份。没有选中的单位会被折算到更小的单位中，小于最小选中单位的部分会被截断。
+/// 当结束时间早于开始时间时，所有的字段都为负数。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Breakdown {
+    pub years: i64,
+    pub months: i64,
+    pub weeks: i64,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub milliseconds: i64,
+}
+
+impl Breakdown {
+    /// English: Break down the interval between two datetimes into the given units
+    ///
+    /// 中文: 将两个日期时间之间的间隔按照给定的单位进行拆分
+    pub fn between(start: &NaiveDateTime, end: &NaiveDateTime, units: Units) -> Breakdown {
+        if end < start {
+            return -Self::between(end, start, units);
+        }
+        Self::calc(start, end, units, |anchor| *end - anchor)
+    }
+
+    /// English: Break down the interval between two zoned datetimes into the given units.
+    /// Years, months, weeks and days follow the wall clock of the start's time zone, while hours
+    /// and smaller units are the elapsed time, so a day crossing a DST transition may have 23 or 25 hours.
+    ///
+    /// 中文: 将两个带时区的日期时间之间的间隔按照给定的单位进行拆分。年、月、周、日按照开始时间所在时区的
+    /// 本地时间计算，小时及更小的单位按照实际经过的时间计算，所以跨越夏令时切换的一天可能有23或25个小时。
+    pub fn between_tz<Tz: TimeZone>(
+        start: &DateTime<Tz>,
+        end: &DateTime<Tz>,
+        units: Units,
+    ) -> Breakdown {
+        if end < start {
+            return -Self::between_tz(end, start, units);
+        }
+        let tz = start.timezone();
+        let local_end = end.with_timezone(&tz).naive_local();
+        Self::calc(&start.naive_local(), &local_end, units, |anchor| {
+            match tz.from_local_datetime(&anchor).earliest() {
+                Some(anchor) => end.clone().signed_duration_since(anchor),
+                None => local_end - anchor,
+            }
+        })
+    }
+
+    /// `start`不晚于`end`，`elapsed`计算从给定的本地时间到结束时间实际经过的时长
+    fn calc(
+        start: &NaiveDateTime,
+        end: &NaiveDateTime,
+        units: Units,
+        elapsed: impl Fn(NaiveDateTime) -> Duration,
+    ) -> Breakdown {
+        let mut breakdown = Breakdown::default();
+        let mut anchor = *start;
+        if units.contains(Units::YEARS) || units.contains(Units::MONTHS) {
+            let months = whole_months(start, end);
+            if units.contains(Units::YEARS) {
+                breakdown.years = months / 12;
+            }
+            if units.contains(Units::MONTHS) {
+                breakdown.months = months - breakdown.years * 12;
+            }
+            anchor = add_months(start, breakdown.years * 12 + breakdown.months);
+        }
+        if units.contains(Units::WEEKS) || units.contains(Units::DAYS) {
+            let days = (*end - anchor).num_days();
+            if units.contains(Units::WEEKS) {
+                breakdown.weeks = days / 7;
+            }
+            if units.contains(Units::DAYS) {
+                breakdown.days = days - breakdown.weeks * 7;
+            }
+            anchor += Duration::days(breakdown.weeks * 7 + breakdown.days);
+        }
+        let mut rest = nanos(elapsed(anchor));
+        let fields = [
+            (Units::HOURS, NANOS_PER_HOUR, &mut breakdown.hours),
+            (Units::MINUTES, NANOS_PER_MINUTE, &mut breakdown.minutes),
+            (Units::SECONDS, NANOS_PER_SECOND, &mut breakdown.seconds),
+            (
+                Units::MILLISECONDS,
+                NANOS_PER_MILLISECOND,
+                &mut breakdown.milliseconds,
+            ),
+        ];
+        for (unit, nanos, field) in fields {
+            if units.contains(unit) {
+                *field = (rest / nanos) as i64;
+                rest %= nanos;
+            }
+        }
+        breakdown
+    }
+
+    /// English: Whether all of the fields are zero
+    ///
+    /// 中文: 是否所有的字段都为零
+    pub fn is_zero(&self) -> bool {
+        *self == Breakdown::default()
+    }
+
+    /// English: The fields with their English names, from the largest unit to the smallest one
+    ///
+    /// 中文: 从最大的单位到最小的单位，依次返回各个字段及其英文名称
+    pub fn fields(&self) -> [(i64, &'static str); 8] {
+        [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.weeks, "week"),
+            (self.days, "day"),
+            (self.hours, "hour"),
+            (self.minutes, "minute"),
+            (self.seconds, "second"),
+            (self.milliseconds, "millisecond"),
+        ]
+    }
+}
+
+impl Neg for Breakdown {
+    type Output = Breakdown;
+
+    fn neg(self) -> Self::Output {
+        Breakdown {
+            years: -self.years,
+            months: -self.months,
+            weeks: -self.weeks,
+            days: -self.days,
+            hours: -self.hours,
+            minutes: -self.minutes,
+            seconds: -self.seconds,
+            milliseconds: -self.milliseconds,
+        }
+    }
+}
+
+impl Display for Breakdown {
+    /// 输出形如`2 years 3 months 4 days 5 hours`的字符串，值为零的字段会被省略
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0 seconds");
+        }
+        let parts = self
+            .fields()
+            .into_iter()
+            .filter(|(value, _)| *value != 0)
+            .map(|(value, name)| {
+                let plural = if value.abs() == 1 { "" } else { "s" };
+                format!("{} {}{}", value, name, plural)
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// English: Break down the interval between two datetimes into the given units, the same as
+/// [`Breakdown::between`]
+///
+/// 中文: 将两个日期时间之间的间隔按照给定的单位进行拆分，与[`Breakdown::between`]相同
+pub fn breakdown(start: &NaiveDateTime, end: &NaiveDateTime, units: Units) -> Breakdown {
+    Breakdown::between(start, end, units)
+}
+
+/// English: Break down the interval between two zoned datetimes into the given units, the same as
+/// [`Breakdown::between_tz`]
+///
+/// 中文: 将两个带时区的日期时间之间的间隔按照给定的单位进行拆分，与[`Breakdown::between_tz`]相同
+pub fn breakdown_tz<Tz: TimeZone>(
+    start: &DateTime<Tz>,
+    end: &DateTime<Tz>,
+    units: Units,
+) -> Breakdown {
+    Breakdown::between_tz(start, end, units)
+}
+
+/// 按照月末对齐的方式增加月数
+fn add_months(datetime: &NaiveDateTime, months: i64) -> NaiveDateTime {
+    shift_months(&datetime.date(), months, OverflowPolicy::Clamp)
+        .unwrap()
+        .and_time(datetime.time())
+}
+
+/// 计算`start`到`end`之间的完整月数，`start`不晚于`end`
+fn whole_months(start: &NaiveDateTime, end: &NaiveDateTime) -> i64 {
+    let mut months =
+        (end.year() - start.year()) as i64 * 12 + end.month() as i64 - start.month() as i64;
+    while months > 0 && add_months(start, months) > *end {
+        months -= 1;
+    }
+    months
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate};
+
+    fn datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_between_all_units() {
+        let start = datetime(2021, 1, 31, 8, 0);
+        let end = datetime(2023, 5, 14, 13, 30) + Duration::milliseconds(1500);
+        let breakdown = Breakdown::between(&start, &end, Units::ALL);
+        assert_eq!(
+            breakdown,
+            Breakdown {
+                years: 2,
+                months: 3,
+                weeks: 2,
+                days: 0,
+                hours: 5,
+                minutes: 30,
+                seconds: 1,
+                milliseconds: 500,
+            }
+        );
+    }
+
+    #[test]
+    fn test_between_borrow_month() {
+        let start = datetime(2023, 1, 20, 18, 0);
+        let end = datetime(2023, 3, 10, 6, 0);
+        let breakdown =
+            Breakdown::between(&start, &end, Units::MONTHS | Units::DAYS | Units::HOURS);
+        assert_eq!(breakdown.months, 1);
+        assert_eq!(breakdown.days, 17);
+        assert_eq!(breakdown.hours, 12);
+    }
+
+    #[test]
+    fn test_between_selected_units() {
+        let start = datetime(2023, 1, 1, 0, 0);
+        let end = datetime(2023, 3, 2, 5, 45);
+        let breakdown = Breakdown::between(&start, &end, Units::DAYS | Units::HOURS);
+        assert_eq!(
+            breakdown,
+            Breakdown {
+                days: 60,
+                hours: 5,
+                ..Breakdown::default()
+            }
+        );
+        let breakdown = Breakdown::between(&start, &end, Units::HOURS);
+        assert_eq!(breakdown.hours, 60 * 24 + 5);
+        let breakdown = Breakdown::between(&start, &end, Units::YEARS | Units::MINUTES);
+        assert_eq!(breakdown.years, 0);
+        assert_eq!(breakdown.minutes, (60 * 24 + 5) * 60 + 45);
+    }
+
+    #[test]
+    fn test_between_negative() {
+        let start = datetime(2023, 3, 2, 5, 45);
+        let end = datetime(2023, 1, 1, 0, 0);
+        let breakdown = Breakdown::between(&start, &end, Units::MONTHS | Units::DAYS);
+        assert_eq!(breakdown.months, -2);
+        assert_eq!(breakdown.days, -1);
+    }
+
+    #[test]
+    fn test_between_tz() {
+        let east = FixedOffset::east_opt(8 * 3600).unwrap();
+        let west = FixedOffset::west_opt(5 * 3600).unwrap();
+        let start = east
+            .from_local_datetime(&datetime(2023, 1, 1, 8, 0))
+            .unwrap();
+        let end = west
+            .from_local_datetime(&datetime(2023, 2, 1, 0, 0))
+            .unwrap();
+        let breakdown = Breakdown::between_tz(&start, &end, Units::ALL);
+        assert_eq!(breakdown.months, 1);
+        assert_eq!(breakdown.hours, 5);
+    }
+
+    #[test]
+    fn test_breakdown_fn() {
+        let start = datetime(2023, 1, 1, 0, 0);
+        let end = datetime(2023, 3, 2, 5, 45);
+        let units = Units::DAYS | Units::HOURS;
+        assert_eq!(
+            breakdown(&start, &end, units),
+            Breakdown::between(&start, &end, units)
+        );
+        let east = FixedOffset::east_opt(8 * 3600).unwrap();
+        let start = east.from_local_datetime(&start).unwrap();
+        let end = east.from_local_datetime(&end).unwrap();
+        assert_eq!(
+            breakdown_tz(&start, &end, units),
+            Breakdown::between_tz(&start, &end, units)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let start = datetime(2020, 1, 1, 0, 0);
+        let end = datetime(2022, 4, 5, 5, 0);
+        let breakdown = Breakdown::between(&start, &end, Units::ALL - Units::WEEKS);
+        assert_eq!(breakdown.to_string(), "2 years 3 months 4 days 5 hours");
+        let breakdown = Breakdown::between(&end, &start, Units::YEARS);
+        assert_eq!(breakdown.to_string(), "-2 years");
+        assert_eq!(Breakdown::default().to_string(), "0 seconds");
+    }
+}
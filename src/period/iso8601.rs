@@ -0,0 +1,708 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat};
+
+use super::Period;
+use crate::day::DayHelper;
+use crate::duration::parse_amount;
+use crate::error::DateUtilsError;
+use crate::hour::HourHelper;
+use crate::minute::MinuteHelper;
+use crate::month::MonthHelper;
+use crate::rounding::{NANOS_PER_DAY, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SECOND, nanos};
+use crate::second::SecondHelper;
+
+/// English: An ISO 8601 duration, such as `P3Y6M4DT12H30M5S` or `P2W`.
+/// A fractional value is allowed on the last component except years and months, and it is carried
+/// to the smaller components, so `PT1.5H` is the same as `PT1H30M`.
+///
+/// 中文: ISO 8601格式的时长，如`P3Y6M4DT12H30M5S`或`P2W`。除年和月之外，最后一个部分可以是小数，
+/// 小数部分会被折算到更小的部分中，所以`PT1.5H`与`PT1H30M`相同
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IsoDuration {
+    /// English: Whether the duration is negative, written as a leading `-` such as `-P1D`
+    ///
+    /// 中文: 时长是否为负数，以开头的`-`表示，如`-P1D`
+    pub negative: bool,
+    /// English: The number of years
+    ///
+    /// 中文: 年数
+    pub years: u32,
+    /// English: The number of months
+    ///
+    /// 中文: 月数
+    pub months: u32,
+    /// English: The number of weeks
+    ///
+    /// 中文: 周数
+    pub weeks: u32,
+    /// English: The number of days
+    ///
+    /// 中文: 天数
+    pub days: u32,
+    /// English: The number of hours
+    ///
+    /// 中文: 小时数
+    pub hours: u32,
+    /// English: The number of minutes
+    ///
+    /// 中文: 分钟数
+    pub minutes: u32,
+    /// English: The number of whole seconds
+    ///
+    /// 中文: 整秒数
+    pub seconds: u32,
+    /// English: The sub-second part in nanoseconds, from 0 to 999,999,999, e.g. 500,000,000 for `PT1.5S`
+    ///
+    /// 中文: 不足一秒的部分，以纳秒表示，取值为0到999,999,999，如`PT1.5S`中为500,000,000
+    pub nanoseconds: u32,
+}
+
+impl IsoDuration {
+    /// English: Whether all of the components are zero
+    ///
+    /// 中文: 是否所有的部分都为零
+    pub fn is_zero(&self) -> bool {
+        IsoDuration {
+            negative: false,
+            ..*self
+        } == IsoDuration::default()
+    }
+
+    /// English: Convert to an exact duration, a week is 7 days and a day is 24 hours.
+    /// Return `None` when there are years or months, whose length depends on the calendar.
+    ///
+    /// 中文: 转换为精确的时长，一周为7天，一天为24小时。包含年或月时返回`None`，因为它们的长度取决于日历
+    pub fn to_duration(&self) -> Option<Duration> {
+        if self.years != 0 || self.months != 0 {
+            return None;
+        }
+        let nanos = (self.weeks as i128 * 7 + self.days as i128) * NANOS_PER_DAY
+            + self.hours as i128 * NANOS_PER_HOUR
+            + self.minutes as i128 * NANOS_PER_MINUTE
+            + self.seconds as i128 * NANOS_PER_SECOND
+            + self.nanoseconds as i128;
+        let nanos = if self.negative { -nanos } else { nanos };
+        let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).ok()?;
+        Duration::new(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32)
+    }
+
+    /// English: Convert to a `Period`, weeks are counted as days and the fractional seconds are truncated.
+    /// Return `None` when a component does not fit in the `Period`.
+    ///
+    /// 中文: 转换为`Period`，周会被折算为天，秒的小数部分会被截断。某个部分超出`Period`的范围时返回`None`
+    pub fn to_period(&self) -> Option<Period> {
+        let sign = if self.negative { -1 } else { 1 };
+        Some(Period {
+            year: i16::try_from(self.years).ok()? * sign as i16,
+            month: i8::try_from(self.months).ok()? * sign,
+            day: i8::try_from(self.weeks.checked_mul(7)?.checked_add(self.days)?).ok()? * sign,
+            hour: i8::try_from(self.hours).ok()? * sign,
+            minute: i8::try_from(self.minutes).ok()? * sign,
+            second: i8::try_from(self.seconds).ok()? * sign,
+        })
+    }
+
+    /// English: Add the duration to the datetime, years and months first, then days and the time
+    ///
+    /// 中文: 在日期时间上加上该时长，先加年和月，再加天数和时间
+    pub fn add_to(&self, datetime: &NaiveDateTime) -> Option<NaiveDateTime> {
+        if self.negative {
+            self.sub_components(datetime)
+        } else {
+            self.add_components(datetime)
+        }
+    }
+
+    /// English: Subtract the duration from the datetime, years and months first, then days and the time
+    ///
+    /// 中文: 从日期时间上减去该时长，先减年和月，再减天数和时间
+    pub fn sub_from(&self, datetime: &NaiveDateTime) -> Option<NaiveDateTime> {
+        if self.negative {
+            self.add_components(datetime)
+        } else {
+            self.sub_components(datetime)
+        }
+    }
+
+    fn add_components(&self, datetime: &NaiveDateTime) -> Option<NaiveDateTime> {
+        datetime
+            .add_months_opt(self.years as i64 * 12 + self.months as i64)?
+            .add_days_opt(self.weeks as u64 * 7 + self.days as u64)?
+            .add_hours_opt(self.hours)?
+            .add_minutes_opt(self.minutes)?
+            .add_seconds_opt(self.seconds as i64)?
+            .checked_add_signed(Duration::nanoseconds(self.nanoseconds as i64))
+    }
+
+    fn sub_components(&self, datetime: &NaiveDateTime) -> Option<NaiveDateTime> {
+        datetime
+            .sub_months_opt(self.years as i64 * 12 + self.months as i64)?
+            .sub_days_opt(self.weeks as u64 * 7 + self.days as u64)?
+            .sub_hours_opt(self.hours)?
+            .sub_minutes_opt(self.minutes)?
+            .sub_seconds_opt(self.seconds as i64)?
+            .checked_sub_signed(Duration::nanoseconds(self.nanoseconds as i64))
+    }
+
+    /// 在带偏移量的日期时间上按本地时间加上（`forward`为`true`）或减去该时长
+    fn shift(
+        &self,
+        datetime: &DateTime<FixedOffset>,
+        forward: bool,
+    ) -> Option<DateTime<FixedOffset>> {
+        let local = datetime.naive_local();
+        let local = if forward {
+            self.add_to(&local)?
+        } else {
+            self.sub_from(&local)?
+        };
+        local.and_local_timezone(*datetime.offset()).single()
+    }
+
+    /// 每个部分都乘以`n`
+    fn times(&self, n: u32) -> Option<IsoDuration> {
+        let nanoseconds = self.nanoseconds as u64 * n as u64;
+        let carry = u32::try_from(nanoseconds / NANOS_PER_SECOND as u64).ok()?;
+        Some(IsoDuration {
+            negative: self.negative,
+            years: self.years.checked_mul(n)?,
+            months: self.months.checked_mul(n)?,
+            weeks: self.weeks.checked_mul(n)?,
+            days: self.days.checked_mul(n)?,
+            hours: self.hours.checked_mul(n)?,
+            minutes: self.minutes.checked_mul(n)?,
+            seconds: self.seconds.checked_mul(n)?.checked_add(carry)?,
+            nanoseconds: (nanoseconds % NANOS_PER_SECOND as u64) as u32,
+        })
+    }
+}
+
+impl From<Duration> for IsoDuration {
+    /// 转换为以天、小时、分钟、秒表示的时长，超出`u32`范围的天数会被截断
+    fn from(duration: Duration) -> Self {
+        let total = nanos(duration);
+        let mut rest = total.abs();
+        let mut take = |unit: i128| {
+            let value = rest / unit;
+            rest %= unit;
+            value as u32
+        };
+        IsoDuration {
+            negative: total < 0,
+            days: take(NANOS_PER_DAY),
+            hours: take(NANOS_PER_HOUR),
+            minutes: take(NANOS_PER_MINUTE),
+            seconds: take(NANOS_PER_SECOND),
+            nanoseconds: take(1),
+            ..IsoDuration::default()
+        }
+    }
+}
+
+impl From<Period> for IsoDuration {
+    /// 各部分的符号应当一致，只要有一个部分为负数，结果就是负的时长
+    fn from(period: Period) -> Self {
+        let fields = [
+            period.month,
+            period.day,
+            period.hour,
+            period.minute,
+            period.second,
+        ];
+        IsoDuration {
+            negative: period.year < 0 || fields.iter().any(|field| *field < 0),
+            years: period.year.unsigned_abs() as u32,
+            months: period.month.unsigned_abs() as u32,
+            days: period.day.unsigned_abs() as u32,
+            hours: period.hour.unsigned_abs() as u32,
+            minutes: period.minute.unsigned_abs() as u32,
+            seconds: period.second.unsigned_abs() as u32,
+            ..IsoDuration::default()
+        }
+    }
+}
+
+impl FromStr for IsoDuration {
+    type Err = DateUtilsError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || DateUtilsError::Parse(input.to_string());
+        let (negative, text) = match input.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let mut text = text.strip_prefix('P').ok_or_else(error)?;
+        let mut duration = IsoDuration {
+            negative,
+            ..IsoDuration::default()
+        };
+        let mut in_time = false;
+        let mut last_order = 0;
+        let mut fraction = None;
+        while !text.is_empty() {
+            if let Some(rest) = text.strip_prefix('T') {
+                if in_time || rest.is_empty() {
+                    return Err(error());
+                }
+                in_time = true;
+                text = rest;
+                continue;
+            }
+            if fraction.is_some() {
+                return Err(error());
+            }
+            let end = text
+                .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+                .ok_or_else(error)?;
+            let (number, rest) = text.split_at(end);
+            let designator = rest.chars().next().ok_or_else(error)?;
+            text = &rest[designator.len_utf8()..];
+            let (order, unit, field) = match (in_time, designator) {
+                (false, 'Y') => (1, 0, &mut duration.years),
+                (false, 'M') => (2, 0, &mut duration.months),
+                (false, 'W') => (3, 7 * NANOS_PER_DAY, &mut duration.weeks),
+                (false, 'D') => (4, NANOS_PER_DAY, &mut duration.days),
+                (true, 'H') => (5, NANOS_PER_HOUR, &mut duration.hours),
+                (true, 'M') => (6, NANOS_PER_MINUTE, &mut duration.minutes),
+                (true, 'S') => (7, NANOS_PER_SECOND, &mut duration.seconds),
+                _ => return Err(error()),
+            };
+            if order <= last_order {
+                return Err(error());
+            }
+            last_order = order;
+            let number = number.replace(',', ".");
+            let (integer, decimal) = number.split_once('.').unwrap_or((&number, ""));
+            if integer.is_empty() || !integer.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(error());
+            }
+            *field = integer.parse().map_err(|_| DateUtilsError::OutOfRange)?;
+            if !decimal.is_empty() {
+                if unit == 0 {
+                    return Err(error());
+                }
+                fraction = Some(parse_amount(&format!("0.{}", decimal), unit).ok_or_else(error)?);
+            }
+        }
+        if last_order == 0 {
+            return Err(error());
+        }
+        if let Some(mut rest) = fraction {
+            let fields = [
+                (NANOS_PER_DAY, &mut duration.days),
+                (NANOS_PER_HOUR, &mut duration.hours),
+                (NANOS_PER_MINUTE, &mut duration.minutes),
+                (NANOS_PER_SECOND, &mut duration.seconds),
+                (1, &mut duration.nanoseconds),
+            ];
+            for (unit, field) in fields {
+                *field += (rest / unit) as u32;
+                rest %= unit;
+            }
+        }
+        Ok(duration)
+    }
+}
+
+impl Display for IsoDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.negative && !self.is_zero() {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        if self.is_zero() {
+            return write!(f, "T0S");
+        }
+        let date = [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ];
+        for (value, designator) in date {
+            if value != 0 {
+                write!(f, "{}{}", value, designator)?;
+            }
+        }
+        if self.hours == 0 && self.minutes == 0 && self.seconds == 0 && self.nanoseconds == 0 {
+            return Ok(());
+        }
+        write!(f, "T")?;
+        if self.hours != 0 {
+            write!(f, "{}H", self.hours)?;
+        }
+        if self.minutes != 0 {
+            write!(f, "{}M", self.minutes)?;
+        }
+        if self.nanoseconds != 0 {
+            let fraction = format!("{:09}", self.nanoseconds);
+            write!(f, "{}.{}S", self.seconds, fraction.trim_end_matches('0'))?;
+        } else if self.seconds != 0 {
+            write!(f, "{}S", self.seconds)?;
+        }
+        Ok(())
+    }
+}
+
+/// 解析ISO 8601格式的日期时间，没有时区偏移量时按UTC处理
+fn parse_datetime(input: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(input)
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S%.f")
+                .ok()
+                .map(|datetime| datetime.and_utc().fixed_offset())
+        })
+        .or_else(|| {
+            NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|datetime| datetime.and_utc().fixed_offset())
+        })
+}
+
+fn format_datetime(datetime: &DateTime<FixedOffset>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// English: An ISO 8601 time interval, written as `start/end`, `start/duration` or `duration/end`.
+///
+/// 中文: ISO 8601格式的时间间隔，写作`start/end`、`start/duration`或`duration/end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsoInterval {
+    /// English: `start/end`
+    ///
+    /// 中文: `开始/结束`
+    StartEnd(DateTime<FixedOffset>, DateTime<FixedOffset>),
+    /// English: `start/duration`
+    ///
+    /// 中文: `开始/时长`
+    StartDuration(DateTime<FixedOffset>, IsoDuration),
+    /// English: `duration/end`
+    ///
+    /// 中文: `时长/结束`
+    DurationEnd(IsoDuration, DateTime<FixedOffset>),
+}
+
+impl IsoInterval {
+    /// English: The start of the interval, `None` when it can not be represented
+    ///
+    /// 中文: 时间间隔的开始时间，无法表示时返回`None`
+    pub fn start(&self) -> Option<DateTime<FixedOffset>> {
+        match self {
+            IsoInterval::StartEnd(start, _) | IsoInterval::StartDuration(start, _) => Some(*start),
+            IsoInterval::DurationEnd(duration, end) => duration.shift(end, false),
+        }
+    }
+
+    /// English: The end of the interval, `None` when it can not be represented
+    ///
+    /// 中文: 时间间隔的结束时间，无法表示时返回`None`
+    pub fn end(&self) -> Option<DateTime<FixedOffset>> {
+        match self {
+            IsoInterval::StartEnd(_, end) | IsoInterval::DurationEnd(_, end) => Some(*end),
+            IsoInterval::StartDuration(start, duration) => duration.shift(start, true),
+        }
+    }
+
+    /// English: The exact length of the interval
+    ///
+    /// 中文: 时间间隔的精确长度
+    pub fn duration(&self) -> Option<Duration> {
+        Some(self.end()? - self.start()?)
+    }
+
+    /// 重复时间间隔时每次前进的时长，以及是否从开始时间向后推算
+    fn step(&self) -> (DateTime<FixedOffset>, IsoDuration, bool) {
+        match self {
+            IsoInterval::StartEnd(start, end) => (*start, IsoDuration::from(*end - *start), true),
+            IsoInterval::StartDuration(start, duration) => (*start, *duration, true),
+            IsoInterval::DurationEnd(duration, end) => (*end, *duration, false),
+        }
+    }
+}
+
+impl FromStr for IsoInterval {
+    type Err = DateUtilsError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || DateUtilsError::Parse(input.to_string());
+        let (first, second) = input.split_once('/').ok_or_else(error)?;
+        let is_duration = |text: &str| text.trim_start_matches(['-', '+']).starts_with('P');
+        let interval = match (is_duration(first), is_duration(second)) {
+            (false, false) => IsoInterval::StartEnd(
+                parse_datetime(first).ok_or_else(error)?,
+                parse_datetime(second).ok_or_else(error)?,
+            ),
+            (false, true) => IsoInterval::StartDuration(
+                parse_datetime(first).ok_or_else(error)?,
+                second.parse().map_err(|_| error())?,
+            ),
+            (true, false) => IsoInterval::DurationEnd(
+                first.parse().map_err(|_| error())?,
+                parse_datetime(second).ok_or_else(error)?,
+            ),
+            (true, true) => return Err(error()),
+        };
+        Ok(interval)
+    }
+}
+
+impl Display for IsoInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsoInterval::StartEnd(start, end) => {
+                write!(f, "{}/{}", format_datetime(start), format_datetime(end))
+            }
+            IsoInterval::StartDuration(start, duration) => {
+                write!(f, "{}/{}", format_datetime(start), duration)
+            }
+            IsoInterval::DurationEnd(duration, end) => {
+                write!(f, "{}/{}", duration, format_datetime(end))
+            }
+        }
+    }
+}
+
+/// English: An ISO 8601 repeating interval, such as `R5/2024-01-01T00:00:00Z/P1D`.
+/// `repetitions` is `None` when the number of repetitions is unbounded (`R/...` or `R-1/...`).
+///
+/// 中文: ISO 8601格式的重复时间间隔，如`R5/2024-01-01T00:00:00Z/P1D`。
+/// 重复次数不限（`R/...`或`R-1/...`）时`repetitions`为`None`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsoRepeatingInterval {
+    pub repetitions: Option<u32>,
+    pub interval: IsoInterval,
+}
+
+impl IsoRepeatingInterval {
+    /// English: The occurrences of the repeating interval. For `start/...` they are the starts of
+    /// each repetition counting forwards, for `duration/end` they are the ends counting backwards.
+    /// The n-th occurrence adds n times the duration to the original datetime, so a month-end
+    /// start does not drift.
+    ///
+    /// 中文: 重复时间间隔的各次发生时间。对于`开始/...`，依次返回每次重复的开始时间；对于`时长/结束`，
+    /// 从结束时间开始向前依次返回每次重复的结束时间。第n次发生时间是在原始时间上加上n倍的时长，
+    /// 所以月末开始的时间不会发生偏移
+    pub fn occurrences(&self) -> IsoOccurrences {
+        let (anchor, step, forward) = self.interval.step();
+        IsoOccurrences {
+            anchor,
+            step,
+            forward,
+            index: 0,
+            repetitions: self.repetitions,
+        }
+    }
+}
+
+impl FromStr for IsoRepeatingInterval {
+    type Err = DateUtilsError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || DateUtilsError::Parse(input.to_string());
+        let (repetitions, interval) = input
+            .strip_prefix('R')
+            .and_then(|rest| rest.split_once('/'))
+            .ok_or_else(error)?;
+        let repetitions = match repetitions {
+            "" | "-1" => None,
+            count => Some(count.parse().map_err(|_| error())?),
+        };
+        let interval = interval.parse().map_err(|_| error())?;
+        Ok(IsoRepeatingInterval {
+            repetitions,
+            interval,
+        })
+    }
+}
+
+impl Display for IsoRepeatingInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.repetitions {
+            Some(repetitions) => write!(f, "R{}/{}", repetitions, self.interval),
+            None => write!(f, "R/{}", self.interval),
+        }
+    }
+}
+
+/// English: The iterator of the occurrences of an [`IsoRepeatingInterval`]
+///
+/// 中文: [`IsoRepeatingInterval`]各次发生时间的迭代器
+#[derive(Debug, Clone)]
+pub struct IsoOccurrences {
+    anchor: DateTime<FixedOffset>,
+    step: IsoDuration,
+    forward: bool,
+    index: u32,
+    repetitions: Option<u32>,
+}
+
+impl Iterator for IsoOccurrences {
+    type Item = DateTime<FixedOffset>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self
+            .repetitions
+            .is_some_and(|repetitions| self.index >= repetitions)
+        {
+            return None;
+        }
+        let occurrence = self
+            .step
+            .times(self.index)?
+            .shift(&self.anchor, self.forward)?;
+        self.index += 1;
+        Some(occurrence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(input: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        let duration: IsoDuration = "P3Y6M4DT12H30M5S".parse().unwrap();
+        assert_eq!(
+            duration,
+            IsoDuration {
+                years: 3,
+                months: 6,
+                days: 4,
+                hours: 12,
+                minutes: 30,
+                seconds: 5,
+                ..IsoDuration::default()
+            }
+        );
+        let duration: IsoDuration = "P2W".parse().unwrap();
+        assert_eq!(duration.to_duration(), Some(Duration::days(14)));
+        let duration: IsoDuration = "PT1.5H".parse().unwrap();
+        assert_eq!(duration.to_duration(), Some(Duration::minutes(90)));
+        let duration: IsoDuration = "-PT0,25S".parse().unwrap();
+        assert_eq!(duration.to_duration(), Some(Duration::milliseconds(-250)));
+    }
+
+    #[test]
+    fn test_parse_duration_error() {
+        for input in [
+            "", "P", "PT", "3Y", "P1.5Y", "P1M1Y", "PT1.5H3M", "P1H", "PT1D", "PxD",
+        ] {
+            assert_eq!(
+                input.parse::<IsoDuration>(),
+                Err(DateUtilsError::Parse(input.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        for input in ["P3Y6M4DT12H30M5S", "P2W", "PT0.5S", "-P1D", "PT0S"] {
+            assert_eq!(input.parse::<IsoDuration>().unwrap().to_string(), input);
+        }
+        assert_eq!(
+            IsoDuration::from(Duration::minutes(-90)).to_string(),
+            "-PT1H30M"
+        );
+    }
+
+    #[test]
+    fn test_duration_period() {
+        let duration: IsoDuration = "P1Y2M1W3DT4H".parse().unwrap();
+        let period = duration.to_period().unwrap();
+        assert_eq!(period.year(), 1);
+        assert_eq!(period.month(), 2);
+        assert_eq!(period.day(), 10);
+        assert_eq!(period.hour(), 4);
+        assert_eq!(IsoDuration::from(period).to_string(), "P1Y2M10DT4H");
+        assert_eq!(duration.to_duration(), None);
+    }
+
+    #[test]
+    fn test_add_to() {
+        let duration: IsoDuration = "P1M1DT1H".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2023, 1, 31)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 3, 1)
+            .unwrap()
+            .and_hms_opt(1, 0, 0)
+            .unwrap();
+        assert_eq!(duration.add_to(&start), Some(end));
+        assert_eq!(
+            duration.sub_from(&end),
+            NaiveDate::from_ymd_opt(2023, 1, 31)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval: IsoInterval = "2024-01-01T00:00:00+08:00/P1M".parse().unwrap();
+        assert_eq!(interval.end(), Some(datetime("2024-02-01T00:00:00+08:00")));
+        assert_eq!(interval.duration(), Some(Duration::days(31)));
+        assert_eq!(interval.to_string(), "2024-01-01T00:00:00+08:00/P1M");
+
+        let interval: IsoInterval = "PT1H/2024-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(interval.start(), Some(datetime("2023-12-31T23:00:00Z")));
+
+        let interval: IsoInterval = "2024-01-01/2024-01-03T12:00:00Z".parse().unwrap();
+        assert_eq!(interval.duration(), Some(Duration::hours(60)));
+        assert_eq!(
+            interval.to_string(),
+            "2024-01-01T00:00:00Z/2024-01-03T12:00:00Z"
+        );
+
+        assert!("P1D/P2D".parse::<IsoInterval>().is_err());
+        assert!("2024-01-01".parse::<IsoInterval>().is_err());
+    }
+
+    #[test]
+    fn test_repeating_interval() {
+        let interval: IsoRepeatingInterval = "R5/2024-01-01T00:00:00Z/P1D".parse().unwrap();
+        assert_eq!(interval.repetitions, Some(5));
+        assert_eq!(interval.to_string(), "R5/2024-01-01T00:00:00Z/P1D");
+        let occurrences = interval.occurrences().collect::<Vec<_>>();
+        assert_eq!(occurrences.len(), 5);
+        assert_eq!(occurrences[4], datetime("2024-01-05T00:00:00Z"));
+
+        let interval: IsoRepeatingInterval = "R/2024-01-31T00:00:00Z/P1M".parse().unwrap();
+        let occurrences = interval.occurrences().take(3).collect::<Vec<_>>();
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime("2024-01-31T00:00:00Z"),
+                datetime("2024-02-29T00:00:00Z"),
+                datetime("2024-03-31T00:00:00Z"),
+            ]
+        );
+
+        let interval: IsoRepeatingInterval = "R2/PT1H/2024-01-01T00:00:00Z".parse().unwrap();
+        let occurrences = interval.occurrences().collect::<Vec<_>>();
+        assert_eq!(
+            occurrences,
+            vec![
+                datetime("2024-01-01T00:00:00Z"),
+                datetime("2023-12-31T23:00:00Z"),
+            ]
+        );
+
+        let interval: IsoRepeatingInterval = "R3/2024-01-01T00:00:00Z/2024-01-01T06:00:00Z"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            interval.occurrences().last(),
+            Some(datetime("2024-01-01T12:00:00Z"))
+        );
+        assert!("R5/P1D".parse::<IsoRepeatingInterval>().is_err());
+    }
+}
@@ -0,0 +1,150 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::CommonHelper;
+use crate::error::DateUtilsError;
+use crate::overflow::{OverflowPolicy, shift_months};
+
+mod breakdown;
+mod iso8601;
+
+pub use breakdown::{Breakdown, Units, breakdown, breakdown_tz};
+pub use iso8601::{IsoDuration, IsoInterval, IsoOccurrences, IsoRepeatingInterval};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Period {
+    year: i16,
+    month: i8,
+    day: i8,
+    hour: i8,
+    minute: i8,
+    second: i8,
+}
+
+impl Period {
+    /// 年数
+    pub fn year(&self) -> i16 {
+        self.year
+    }
+
+    /// 月数
+    pub fn month(&self) -> i8 {
+        self.month
+    }
+
+    /// 天数
+    pub fn day(&self) -> i8 {
+        self.day
+    }
+
+    /// 小时数
+    pub fn hour(&self) -> i8 {
+        self.hour
+    }
+
+    /// 分钟数
+    pub fn minute(&self) -> i8 {
+        self.minute
+    }
+
+    /// 秒数
+    pub fn second(&self) -> i8 {
+        self.second
+    }
+
+    /// 计算两个日期之间的间隔，使用x年x月x日的记录方式
+    pub fn between(one: &NaiveDate, other: &NaiveDate) -> Period {
+        Self::try_between(one, other).unwrap()
+    }
+
+    /// 计算两个日期之间的间隔，使用x年x月x日的记录方式，无法计算时返回错误而不是panic
+    pub fn try_between(one: &NaiveDate, other: &NaiveDate) -> Result<Period, DateUtilsError> {
+        let (another, months) = Self::calc_months(one, other)?;
+        let day = Self::calc_day(other, &another);
+
+        Ok(Period {
+            year: (months / 12) as i16,
+            month: (months % 12) as i8,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        })
+    }
+
+    fn calc_day(other: &NaiveDate, another: &NaiveDate) -> i8 {
+        let duration = *other - *another;
+        duration.num_days() as i8
+    }
+
+    /// 计算完整的月数以及对应的周年日，周年日不存在时取当月最后一天，如2月29日在平年取2月28日
+    fn calc_months(one: &NaiveDate, other: &NaiveDate) -> Result<(NaiveDate, i64), DateUtilsError> {
+        let mut months = (other.year() as i64 - one.year() as i64) * 12 + other.month() as i64
+            - one.month() as i64;
+        let mut another = shift_months(one, months, OverflowPolicy::Clamp)?;
+        if another.after(other) {
+            months -= 1;
+            another = shift_months(one, months, OverflowPolicy::Clamp)?;
+        }
+        Ok((another, months))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn period_between_simple_test() {
+        let start = calc_date(2022, 2, 1).unwrap();
+        let end = calc_date(2023, 3, 2).unwrap();
+        let period = Period::between(&start, &end);
+        let year = period.year;
+        let month = period.month;
+        let day = period.day;
+        assert_eq!(year, 1);
+        assert_eq!(month, 1);
+        assert_eq!(day, 1);
+    }
+
+    fn calc_date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    #[test]
+    fn period_between_less_one_month_test() {
+        let start = calc_date(2022, 2, 10).unwrap();
+        let end = calc_date(2022, 3, 9).unwrap();
+        let period = Period::between(&start, &end);
+        let year = period.year;
+        let month = period.month;
+        let day = period.day;
+        assert_eq!(year, 0);
+        assert_eq!(month, 0);
+        assert_eq!(day, 27);
+    }
+    #[test]
+    fn period_try_between_leap_day_test() {
+        let start = calc_date(2020, 2, 29).unwrap();
+        let end = calc_date(2021, 3, 1).unwrap();
+        let period = Period::try_between(&start, &end).unwrap();
+        assert_eq!(period.year, 1);
+        assert_eq!(period.month, 0);
+        assert_eq!(period.day, 1);
+        assert_eq!(Period::between(&start, &end), period);
+
+        let end = calc_date(2021, 2, 28).unwrap();
+        let period = Period::between(&start, &end);
+        assert_eq!(period.year, 1);
+        assert_eq!(period.month, 0);
+        assert_eq!(period.day, 0);
+    }
+
+    #[test]
+    fn period_between_test() {
+        let start = calc_date(2022, 8, 28).unwrap();
+        let end = calc_date(2035, 3, 10).unwrap();
+        let period = Period::between(&start, &end);
+        assert_eq!(period.year, 12);
+        assert_eq!(period.month, 6);
+        assert_eq!(period.day, 10);
+    }
+}
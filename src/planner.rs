@@ -0,0 +1,166 @@
+use std::ops::{Range, RangeInclusive};
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+use crate::day::DayHelper;
+use crate::dst::{AmbiguityPolicy, GapPolicy, resolve_local};
+use crate::range::DateRange;
+use crate::week_convention::WeekConvention;
+
+/// English: The working hours of a participant in a time zone, such as 09:00 to 18:00 from Monday to
+/// Friday. When the end is not after the start, the working hours end on the next day.
+///
+/// 中文: 某个时区中参与者的工作时间，如周一到周五的09:00到18:00。结束时间不晚于开始时间时，工作时间在第二天结束
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingHours<Tz: TimeZone> {
+    zone: Tz,
+    hours: Range<NaiveTime>,
+    convention: WeekConvention,
+}
+
+impl<Tz: TimeZone> WorkingHours<Tz> {
+    /// English: Create the working hours, with Saturday and Sunday as the weekend
+    ///
+    /// 中文: 创建工作时间，周末为周六和周日
+    pub fn new(zone: Tz, hours: Range<NaiveTime>) -> Self {
+        WorkingHours {
+            zone,
+            hours,
+            convention: WeekConvention::WORLD,
+        }
+    }
+
+    /// English: Use the weekend of the week convention, such as Friday and Saturday in `EG`
+    ///
+    /// 中文: 使用周习惯中的周末，如`EG`的周五和周六
+    pub fn with_convention(mut self, convention: WeekConvention) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// English: Get the time zone
+    ///
+    /// 中文: 获取时区
+    pub fn zone(&self) -> &Tz {
+        &self.zone
+    }
+
+    /// English: Get the local working hours
+    ///
+    /// 中文: 获取本地的工作时间
+    pub fn hours(&self) -> &Range<NaiveTime> {
+        &self.hours
+    }
+
+    /// English: Whether the local date is a working day
+    ///
+    /// 中文: 本地日期是否是工作日
+    pub fn is_working_day(&self, date: &NaiveDate) -> bool {
+        !self.convention.is_weekend(date.weekday())
+    }
+
+    /// 本地日期之间（包含两端）每个工作日的UTC工作时间段
+    fn intervals(&self, first: NaiveDate, last: NaiveDate) -> Vec<Range<DateTime<Utc>>> {
+        first
+            .days()
+            .take_while(|date| *date <= last)
+            .filter(|date| self.is_working_day(date))
+            .filter_map(|date| {
+                let end_date = if self.hours.end <= self.hours.start {
+                    date.add_days_opt(1)?
+                } else {
+                    date
+                };
+                let start = self.to_utc(date.and_time(self.hours.start))?;
+                let end = self.to_utc(end_date.and_time(self.hours.end))?;
+                Some(start..end)
+            })
+            .collect()
+    }
+
+    /// 不存在的本地时间向后移动，出现两次的本地时间取较早的一个
+    fn to_utc(&self, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        resolve_local(
+            &self.zone,
+            &local,
+            GapPolicy::ShiftForward,
+            AmbiguityPolicy::Earliest,
+        )
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+    }
+}
+
+impl<Tz: TimeZone> From<(Tz, Range<NaiveTime>)> for WorkingHours<Tz> {
+    fn from((zone, hours): (Tz, Range<NaiveTime>)) -> Self {
+        WorkingHours::new(zone, hours)
+    }
+}
+
+/// English: Convert the UTC time into each of the time zones, such as for a world clock
+///
+/// 中文: 将UTC时间转换到各个时区，如用于世界时钟
+pub fn convert_to_zones<Tz: TimeZone>(instant: &NaiveDateTime, zones: &[Tz]) -> Vec<DateTime<Tz>> {
+    zones
+        .iter()
+        .map(|zone| zone.from_utc_datetime(instant))
+        .collect()
+}
+
+/// English: Find the UTC intervals within the UTC dates (inclusive) in which every participant is
+/// inside the working hours, skipping the weekend of each participant in the local time zone.
+///
+/// 中文: 在UTC日期之间（包含两端）查找所有参与者都处于工作时间的UTC时间段，按照各自时区的本地日期跳过各自的周末
+pub fn common_working_windows<Tz: TimeZone>(
+    dates: RangeInclusive<NaiveDate>,
+    participants: &[WorkingHours<Tz>],
+) -> Vec<Range<DateTime<Utc>>> {
+    let (first, last) = dates.into_inner();
+    let Some(end_date) = last.add_days_opt(1) else {
+        return vec![];
+    };
+    let bounds =
+        first.and_time(NaiveTime::MIN).and_utc()..end_date.and_time(NaiveTime::MIN).and_utc();
+    if bounds.start >= bounds.end {
+        return vec![];
+    }
+    // 本地日期与UTC日期最多相差一天，前后各多取一天
+    let local_first = first.sub_days_opt(1).unwrap_or(first);
+    participants
+        .iter()
+        .map(|participant| participant.intervals(local_first, end_date))
+        .fold(vec![bounds], |windows, intervals| {
+            intersect(&windows, &intervals)
+        })
+        .into_iter()
+        .fold(vec![], |mut windows: Vec<Range<DateTime<Utc>>>, window| {
+            // 合并首尾相接的时间段
+            match windows.last_mut() {
+                Some(previous) if previous.end >= window.start => previous.end = window.end,
+                _ => windows.push(window),
+            }
+            windows
+        })
+}
+
+/// 求两组按照开始时间排序且互不重叠的时间段的交集
+fn intersect(
+    left: &[Range<DateTime<Utc>>],
+    right: &[Range<DateTime<Utc>>],
+) -> Vec<Range<DateTime<Utc>>> {
+    let mut result = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        let start = left[i].start.max(right[j].start);
+        let end = left[i].end.min(right[j].end);
+        if start < end {
+            result.push(start..end);
+        }
+        if left[i].end <= right[j].end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
@@ -1,6 +1,16 @@
-use chrono::{Datelike, NaiveDate};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-use crate::month::MonthHelper;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+use crate::error::DateUtilsError;
+use crate::month::{MonthHelper, month_fraction};
+use crate::overflow::OverflowPolicy;
+use crate::rounding::Rounding;
+
+mod year_quarter;
+
+pub use year_quarter::{YearQuarter, YearQuarters};
 
 pub trait QuarterHelper {
     /// English: Get the first day of the quarter
@@ -37,6 +47,46 @@ pub trait QuarterHelper {
     ///
     /// 中文: 给定日期减去指定的年份季度数。
     fn sub_quarters_opt(&self, quarters: i32) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Add the specified number of year quarters to the given date, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 给定日期增加指定的年份季度数，溢出时返回错误而不是panic
+    fn try_add_quarters(&self, quarters: i32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.add_quarters_opt(quarters)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Subtract the specified number of year quarters from the given date, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 给定日期减去指定的年份季度数，溢出时返回错误而不是panic
+    fn try_sub_quarters(&self, quarters: i32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.sub_quarters_opt(quarters)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Add the specified number of year quarters to the given date with the given overflow policy.
+    ///
+    /// 中文: 按照指定的溢出策略给定日期增加指定的年份季度数
+    fn add_quarters_with(
+        &self,
+        quarters: i32,
+        policy: OverflowPolicy,
+    ) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
+    /// English: Subtract the specified number of year quarters from the given date with the given overflow policy.
+    ///
+    /// 中文: 按照指定的溢出策略给定日期减去指定的年份季度数
+    fn sub_quarters_with(
+        &self,
+        quarters: i32,
+        policy: OverflowPolicy,
+    ) -> Result<Self, DateUtilsError>
     where
         Self: Sized;
     /// English: Get the number of calendar quarters between the given dates.
@@ -47,9 +97,19 @@ pub trait QuarterHelper {
     ///
     /// 中文: 获取两个日期之间的季度数。
     fn diff_quarters(&self, other: &Self) -> i64;
+    /// English: Get the number of quarters between the given dates with the given rounding method.
+    /// The result is negative when the date is before the other one.
+    ///
+    /// 中文: 按照指定的取整方式计算两个日期之间的季度数，当前日期早于另一个日期时结果为负数
+    fn diff_quarters_with(&self, other: &Self, rounding: Rounding) -> i64;
+    /// English: Get the number of quarters between the given dates, including the fractional part.
+    /// The result is negative when the date is before the other one.
+    ///
+    /// 中文: 计算两个日期之间的季度数，包含小数部分，当前日期早于另一个日期时结果为负数
+    fn diff_quarters_f64(&self, other: &Self) -> f64;
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Quarter {
     Q1,
     Q2,
@@ -57,27 +117,130 @@ pub enum Quarter {
     Q4,
 }
 
+impl Quarter {
+    /// English: Get the quarter of the month (1 to 12), return `None` when the month is invalid
+    ///
+    /// 中文: 获取月份(1到12)所在的季度，月份无效时返回`None`
+    pub fn from_month(month: u32) -> Option<Quarter> {
+        match month {
+            1..=3 => Some(Quarter::Q1),
+            4..=6 => Some(Quarter::Q2),
+            7..=9 => Some(Quarter::Q3),
+            10..=12 => Some(Quarter::Q4),
+            _ => None,
+        }
+    }
+
+    /// English: Get the quarter by its number (1 to 4), return `None` when the number is invalid
+    ///
+    /// 中文: 根据序号(1到4)获取季度，序号无效时返回`None`
+    pub fn from_number(number: u32) -> Option<Quarter> {
+        match number {
+            1 => Some(Quarter::Q1),
+            2 => Some(Quarter::Q2),
+            3 => Some(Quarter::Q3),
+            4 => Some(Quarter::Q4),
+            _ => None,
+        }
+    }
+
+    /// English: Get the number of the quarter, from 1 to 4
+    ///
+    /// 中文: 获取季度的序号，取值为1到4
+    pub fn number(&self) -> u32 {
+        *self as u32 + 1
+    }
+
+    /// English: Get the first month of the quarter
+    ///
+    /// 中文: 获取季度的第一个月
+    pub fn first_month(&self) -> u32 {
+        *self as u32 * 3 + 1
+    }
+
+    /// English: Get the last month of the quarter
+    ///
+    /// 中文: 获取季度的最后一个月
+    pub fn last_month(&self) -> u32 {
+        self.first_month() + 2
+    }
+
+    /// English: Get the months of the quarter
+    ///
+    /// 中文: 获取季度包含的月份
+    pub fn months(&self) -> [u32; 3] {
+        let first = self.first_month();
+        [first, first + 1, first + 2]
+    }
+
+    /// English: Get the next quarter, the next quarter of Q4 is Q1
+    ///
+    /// 中文: 获取下一个季度，Q4的下一个季度为Q1
+    pub fn succ(&self) -> Quarter {
+        Quarter::from_number(self.number() % 4 + 1).unwrap()
+    }
+
+    /// English: Get the previous quarter, the previous quarter of Q1 is Q4
+    ///
+    /// 中文: 获取上一个季度，Q1的上一个季度为Q4
+    pub fn pred(&self) -> Quarter {
+        Quarter::from_number((self.number() + 2) % 4 + 1).unwrap()
+    }
+}
+
+impl Display for Quarter {
+    /// 默认输出`Q1`的形式，使用`{:#}`时输出`第一季度`的形式
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            let name = match self {
+                Quarter::Q1 => "第一季度",
+                Quarter::Q2 => "第二季度",
+                Quarter::Q3 => "第三季度",
+                Quarter::Q4 => "第四季度",
+            };
+            write!(f, "{}", name)
+        } else {
+            write!(f, "Q{}", self.number())
+        }
+    }
+}
+
+impl FromStr for Quarter {
+    type Err = DateUtilsError;
+
+    /// 支持`Q1`、`q1`、`1`和`第一季度`的形式
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let text = input.trim();
+        let number = match text {
+            "第一季度" => Some(1),
+            "第二季度" => Some(2),
+            "第三季度" => Some(3),
+            "第四季度" => Some(4),
+            _ => text
+                .strip_prefix(['Q', 'q'])
+                .unwrap_or(text)
+                .parse::<u32>()
+                .ok(),
+        };
+        number
+            .and_then(Quarter::from_number)
+            .ok_or_else(|| DateUtilsError::Parse(input.to_string()))
+    }
+}
+
 impl QuarterHelper for NaiveDate {
     fn begin_of_quarter(&self) -> Self {
-        let quarter = QuarterHelper::quarter(self);
-        let month = match quarter {
-            Quarter::Q1 => 1,
-            Quarter::Q2 => 4,
-            Quarter::Q3 => 7,
-            Quarter::Q4 => 10,
-        };
-        self.with_month(month).unwrap().begin_of_month()
+        let month = QuarterHelper::quarter(self).first_month();
+        // 先取月初再设置月份，避免如5月31日设置为4月时日期不存在
+        self.begin_of_month().with_month(month).unwrap()
     }
 
     fn end_of_quarter(&self) -> Self {
-        let quarter = QuarterHelper::quarter(self);
-        let month = match quarter {
-            Quarter::Q1 => 3,
-            Quarter::Q2 => 6,
-            Quarter::Q3 => 9,
-            Quarter::Q4 => 12,
-        };
-        self.with_month(month).unwrap().end_of_month()
+        let month = QuarterHelper::quarter(self).last_month();
+        self.begin_of_month()
+            .with_month(month)
+            .unwrap()
+            .end_of_month()
     }
 
     fn is_same_quarter(&self, other: &Self) -> bool {
@@ -85,14 +248,7 @@ impl QuarterHelper for NaiveDate {
     }
 
     fn quarter(&self) -> Quarter {
-        let month = self.month();
-        match month {
-            1..=3 => Quarter::Q1,
-            4..=6 => Quarter::Q2,
-            7..=9 => Quarter::Q3,
-            10..=12 => Quarter::Q4,
-            _ => unreachable!(),
-        }
+        Quarter::from_month(self.month()).unwrap()
     }
 
     fn add_quarters(&self, quarters: i32) -> Self {
@@ -117,6 +273,22 @@ impl QuarterHelper for NaiveDate {
         self.sub_months_opt(quarters as i64 * 3)
     }
 
+    fn add_quarters_with(
+        &self,
+        quarters: i32,
+        policy: OverflowPolicy,
+    ) -> Result<Self, DateUtilsError> {
+        self.add_months_with(quarters as i64 * 3, policy)
+    }
+
+    fn sub_quarters_with(
+        &self,
+        quarters: i32,
+        policy: OverflowPolicy,
+    ) -> Result<Self, DateUtilsError> {
+        self.sub_months_with(quarters as i64 * 3, policy)
+    }
+
     fn diff_calendar_quarters(&self, other: &Self) -> i64 {
         let start = self.begin_of_quarter();
         let other = other.begin_of_quarter();
@@ -126,4 +298,97 @@ impl QuarterHelper for NaiveDate {
     fn diff_quarters(&self, other: &Self) -> i64 {
         self.diff_months(other) / 3
     }
+
+    fn diff_quarters_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (months, remainder, unit) = month_fraction(self, other);
+        rounding.apply(
+            months / 3,
+            (months % 3) as i128 * unit + remainder,
+            3 * unit,
+        )
+    }
+
+    fn diff_quarters_f64(&self, other: &Self) -> f64 {
+        self.diff_months_f64(other) / 3.0
+    }
+}
+
+impl QuarterHelper for NaiveDateTime {
+    fn begin_of_quarter(&self) -> Self {
+        self.date().begin_of_quarter().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    fn end_of_quarter(&self) -> Self {
+        self.date()
+            .end_of_quarter()
+            .and_hms_opt(23, 59, 59)
+            .unwrap()
+    }
+
+    fn is_same_quarter(&self, other: &Self) -> bool {
+        self.date().is_same_quarter(&other.date())
+    }
+
+    fn quarter(&self) -> Quarter {
+        QuarterHelper::quarter(&self.date())
+    }
+
+    fn add_quarters(&self, quarters: i32) -> Self {
+        self.add_quarters_opt(quarters).unwrap()
+    }
+
+    fn add_quarters_opt(&self, quarters: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.add_months_opt(quarters as i64 * 3)
+    }
+
+    fn sub_quarters(&self, quarters: i32) -> Self {
+        self.sub_quarters_opt(quarters).unwrap()
+    }
+
+    fn sub_quarters_opt(&self, quarters: i32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.sub_months_opt(quarters as i64 * 3)
+    }
+
+    fn add_quarters_with(
+        &self,
+        quarters: i32,
+        policy: OverflowPolicy,
+    ) -> Result<Self, DateUtilsError> {
+        self.add_months_with(quarters as i64 * 3, policy)
+    }
+
+    fn sub_quarters_with(
+        &self,
+        quarters: i32,
+        policy: OverflowPolicy,
+    ) -> Result<Self, DateUtilsError> {
+        self.sub_months_with(quarters as i64 * 3, policy)
+    }
+
+    fn diff_calendar_quarters(&self, other: &Self) -> i64 {
+        self.date().diff_calendar_quarters(&other.date())
+    }
+
+    fn diff_quarters(&self, other: &Self) -> i64 {
+        self.diff_months(other) / 3
+    }
+
+    fn diff_quarters_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (months, remainder, unit) = month_fraction(self, other);
+        rounding.apply(
+            months / 3,
+            (months % 3) as i128 * unit + remainder,
+            3 * unit,
+        )
+    }
+
+    fn diff_quarters_f64(&self, other: &Self) -> f64 {
+        self.diff_months_f64(other) / 3.0
+    }
 }
@@ -0,0 +1,182 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::DateUtilsError;
+use crate::quarter::Quarter;
+use crate::utils::days_in_month;
+
+/// English: A quarter of a specific year, such as `2024-Q1`
+///
+/// 中文: 某一年中的某个季度，如`2024-Q1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearQuarter {
+    year: i32,
+    quarter: Quarter,
+}
+
+impl YearQuarter {
+    /// English: Create a year quarter
+    ///
+    /// 中文: 创建某年的某个季度
+    pub fn new(year: i32, quarter: Quarter) -> Self {
+        YearQuarter { year, quarter }
+    }
+
+    /// English: Get the year
+    ///
+    /// 中文: 获取年份
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// English: Get the quarter
+    ///
+    /// 中文: 获取季度
+    pub fn quarter(&self) -> Quarter {
+        self.quarter
+    }
+
+    /// English: Get the first day of the quarter
+    ///
+    /// 中文: 获取季度的第一天
+    pub fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.quarter.first_month(), 1).unwrap()
+    }
+
+    /// English: Get the last day of the quarter
+    ///
+    /// 中文: 获取季度的最后一天
+    pub fn last_day(&self) -> NaiveDate {
+        let month = self.quarter.last_month();
+        NaiveDate::from_ymd_opt(self.year, month, days_in_month(self.year, month)).unwrap()
+    }
+
+    /// English: Whether the date is in the quarter
+    ///
+    /// 中文: 日期是否在该季度中
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        YearQuarter::from(*date) == *self
+    }
+
+    /// English: Get the next quarter
+    ///
+    /// 中文: 获取下一个季度
+    pub fn succ(&self) -> YearQuarter {
+        *self + 1
+    }
+
+    /// English: Get the previous quarter
+    ///
+    /// 中文: 获取上一个季度
+    pub fn pred(&self) -> YearQuarter {
+        *self - 1
+    }
+
+    /// English: Iterate over the quarters from `self` to `end`, both inclusive
+    ///
+    /// 中文: 遍历从`self`到`end`的季度，包含两端
+    pub fn iter_to(&self, end: YearQuarter) -> YearQuarters {
+        YearQuarters {
+            next: self.index(),
+            last: end.index(),
+        }
+    }
+
+    /// 以公元0年第一季度为0的季度序号
+    fn index(&self) -> i64 {
+        self.year as i64 * 4 + self.quarter.number() as i64 - 1
+    }
+
+    fn from_index(index: i64) -> YearQuarter {
+        let quarter = Quarter::from_number(index.rem_euclid(4) as u32 + 1).unwrap();
+        YearQuarter::new(index.div_euclid(4) as i32, quarter)
+    }
+}
+
+impl From<NaiveDate> for YearQuarter {
+    fn from(date: NaiveDate) -> Self {
+        YearQuarter::new(date.year(), Quarter::from_month(date.month()).unwrap())
+    }
+}
+
+impl Add<i32> for YearQuarter {
+    type Output = YearQuarter;
+
+    fn add(self, quarters: i32) -> Self::Output {
+        YearQuarter::from_index(self.index() + quarters as i64)
+    }
+}
+
+impl Sub<i32> for YearQuarter {
+    type Output = YearQuarter;
+
+    fn sub(self, quarters: i32) -> Self::Output {
+        YearQuarter::from_index(self.index() - quarters as i64)
+    }
+}
+
+impl Sub<YearQuarter> for YearQuarter {
+    type Output = i64;
+
+    /// 两个季度之间相差的季度数
+    fn sub(self, other: YearQuarter) -> Self::Output {
+        self.index() - other.index()
+    }
+}
+
+impl Display for YearQuarter {
+    /// 默认输出`2024-Q1`的形式，使用`{:#}`时输出`2024年第一季度`的形式
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}年{:#}", self.year, self.quarter)
+        } else {
+            write!(f, "{}-{}", self.year, self.quarter)
+        }
+    }
+}
+
+impl FromStr for YearQuarter {
+    type Err = DateUtilsError;
+
+    /// 支持`2024-Q1`、`2024Q1`和`2024年第一季度`的形式
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || DateUtilsError::Parse(input.to_string());
+        let text = input.trim();
+        let (year, quarter) = match text.split_once('年') {
+            Some(parts) => parts,
+            None => {
+                let index = text.rfind(['Q', 'q']).ok_or_else(error)?;
+                let (year, quarter) = text.split_at(index);
+                (year.strip_suffix('-').unwrap_or(year), quarter)
+            }
+        };
+        let year = year.parse::<i32>().map_err(|_| error())?;
+        let quarter = quarter.parse::<Quarter>().map_err(|_| error())?;
+        Ok(YearQuarter::new(year, quarter))
+    }
+}
+
+/// English: The iterator of year quarters
+///
+/// 中文: 季度的迭代器
+#[derive(Debug, Clone)]
+pub struct YearQuarters {
+    next: i64,
+    last: i64,
+}
+
+impl Iterator for YearQuarters {
+    type Item = YearQuarter;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next > self.last {
+            return None;
+        }
+        let quarter = YearQuarter::from_index(self.next);
+        self.next += 1;
+        Some(quarter)
+    }
+}
@@ -7,6 +7,7 @@ use crate::week::WeekHelper;
 use crate::month::MonthHelper;
 use crate::quarter::QuarterHelper;
 use crate::day::DayHelper;
+use crate::half_year::HalfYearHelper;
 use crate::range::DateRange;
 
 impl DateRange<NaiveDate> for NaiveDate {
@@ -79,6 +80,14 @@ impl DateRange<NaiveDate> for NaiveDate {
             Some(result)
         })
     }
+    fn halves(&self) -> impl Iterator<Item = NaiveDate> {
+        let mut start = self.begin_of_half();
+        from_fn(move || {
+            let result = start;
+            start = start.add_halves(1);
+            Some(result)
+        })
+    }
 }
 fn with_end(mut start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate> {
     from_fn(move || {
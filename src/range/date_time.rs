@@ -2,6 +2,7 @@ use std::iter::from_fn;
 
 use chrono::{NaiveDateTime, Weekday};
 use crate::day::DayHelper;
+use crate::half_year::HalfYearHelper;
 use crate::hour::HourHelper;
 use crate::minute::MinuteHelper;
 use crate::month::MonthHelper;
@@ -89,6 +90,14 @@ impl DateRange<NaiveDateTime> for NaiveDateTime {
             Some(result)
         })
     }
+    fn halves(&self) -> impl Iterator<Item = NaiveDateTime> {
+        let mut start = self.date().begin_of_half();
+        from_fn(move || {
+            let result: NaiveDateTime = start.and_hms_opt(0, 0, 0).unwrap();
+            start = start.add_halves(1);
+            Some(result)
+        })
+    }
 }
 impl TimeRange<NaiveDateTime> for NaiveDateTime {
     fn hours(&self) -> impl Iterator<Item = NaiveDateTime> {
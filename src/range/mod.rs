@@ -2,6 +2,7 @@ use chrono::Weekday;
 
 mod date;
 mod date_time;
+mod zoned;
 
 pub trait DateRange<T> {
     /// 返回表示天数的迭代器
@@ -93,6 +94,10 @@ pub trait DateRange<T> {
     ///
     /// 中文: 获取年份的季度
     fn quarters(&self) -> impl Iterator<Item = T>;
+    /// English: Get the half years, starting from the half year of the date
+    ///
+    /// 中文: 获取从日期所在半年开始的各个半年
+    fn halves(&self) -> impl Iterator<Item = T>;
 }
 
 pub trait TimeRange<T> {
@@ -0,0 +1,56 @@
+use std::iter::from_fn;
+
+use chrono::{DateTime, Duration, TimeZone, Timelike};
+
+use crate::range::TimeRange;
+
+/// 按照经过的时间遍历，夏令时开始当天少一个小时，结束当天多一个小时
+impl<Tz: TimeZone> TimeRange<DateTime<Tz>> for DateTime<Tz> {
+    fn hours(&self) -> impl Iterator<Item = DateTime<Tz>> {
+        let local = self.naive_local();
+        let seconds = local.minute() as i64 * 60 + local.second() as i64;
+        let start = self.clone()
+            - Duration::seconds(seconds)
+            - Duration::nanoseconds(local.nanosecond() as i64);
+        elapsed(start, Duration::hours(1))
+    }
+
+    fn minutes(&self) -> impl Iterator<Item = DateTime<Tz>> {
+        let local = self.naive_local();
+        let start = self.clone()
+            - Duration::seconds(local.second() as i64)
+            - Duration::nanoseconds(local.nanosecond() as i64);
+        elapsed(start, Duration::minutes(1))
+    }
+
+    fn seconds(&self) -> impl Iterator<Item = DateTime<Tz>> {
+        let start = self.clone() - Duration::nanoseconds(self.nanosecond() as i64);
+        elapsed(start, Duration::seconds(1))
+    }
+
+    fn hours_with_iter(&self, end: &Self) -> impl Iterator<Item = DateTime<Tz>> {
+        let end = end.clone();
+        self.hours().take_while(move |date| *date <= end)
+    }
+
+    fn minutes_with_iter(&self, end: &Self) -> impl Iterator<Item = DateTime<Tz>> {
+        let end = end.clone();
+        self.minutes().take_while(move |date| *date <= end)
+    }
+
+    fn seconds_with_iter(&self, end: &Self) -> impl Iterator<Item = DateTime<Tz>> {
+        let end = end.clone();
+        self.seconds().take_while(move |date| *date <= end)
+    }
+}
+
+fn elapsed<Tz: TimeZone>(
+    mut next: DateTime<Tz>,
+    step: Duration,
+) -> impl Iterator<Item = DateTime<Tz>> {
+    from_fn(move || {
+        let result = next.clone();
+        next = next.clone() + step;
+        Some(result)
+    })
+}
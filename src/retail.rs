@@ -0,0 +1,316 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+use crate::error::DateUtilsError;
+use crate::fiscal::FiscalYearNaming;
+use crate::quarter::Quarter;
+use crate::utils::days_in_month;
+use crate::week::WeekHelper;
+
+/// English: The number of weeks in each period of a retail quarter, or 13 periods of 4 weeks.
+///
+/// 中文: 零售季度中各个时期的周数，或者13个每期4周的时期
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetailPattern {
+    /// English: 4-4-5 weeks in each quarter
+    ///
+    /// 中文: 每个季度为4-4-5周
+    P445,
+    /// English: 4-5-4 weeks in each quarter, used by the NRF calendar
+    ///
+    /// 中文: 每个季度为4-5-4周，NRF日历使用该模式
+    P454,
+    /// English: 5-4-4 weeks in each quarter
+    ///
+    /// 中文: 每个季度为5-4-4周
+    P544,
+    /// English: 13 periods of 4 weeks
+    ///
+    /// 中文: 13个时期，每个时期4周
+    Periods13,
+}
+
+/// English: How the last day of a retail year is chosen.
+///
+/// 中文: 零售年最后一天的确定方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearEndRule {
+    /// English: The last given weekday of the year-end month
+    ///
+    /// 中文: 年末月份中最后一个指定的星期几
+    LastWeekdayOfMonth,
+    /// English: The given weekday nearest to the last day of the year-end month, which may be in the next month
+    ///
+    /// 中文: 距离年末月份最后一天最近的指定星期几，可能位于下个月
+    NearestToMonthEnd,
+}
+
+/// English: Which period gets the extra week of a 53-week year.
+///
+/// 中文: 53周的年份中多出的一周放在哪个时期
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeapWeekPlacement {
+    /// English: The first period of the year
+    ///
+    /// 中文: 一年中的第一个时期
+    FirstPeriod,
+    /// English: The last period of the year
+    ///
+    /// 中文: 一年中的最后一个时期
+    LastPeriod,
+}
+
+/// English: A retail (4-4-5, 4-5-4, 5-4-4 or 13-period) calendar, whose years are made up of whole
+/// weeks and end on a fixed weekday, so some years have 53 weeks.
+///
+/// 中文: 零售日历（4-4-5、4-5-4、5-4-4或13期），每年由完整的周组成，并在固定的星期几结束，所以部分年份有53周
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetailCalendar {
+    pattern: RetailPattern,
+    year_end_month: u32,
+    year_end_weekday: Weekday,
+    rule: YearEndRule,
+    leap_week: LeapWeekPlacement,
+    naming: FiscalYearNaming,
+}
+
+impl RetailCalendar {
+    /// English: The NRF 4-5-4 calendar, whose year ends on the Saturday nearest to January 31st and is
+    /// named after the year in which it starts.
+    ///
+    /// 中文: NRF 4-5-4日历，每年在距离1月31日最近的星期六结束，以开始的年份命名
+    pub const NRF: RetailCalendar = RetailCalendar {
+        pattern: RetailPattern::P454,
+        year_end_month: 1,
+        year_end_weekday: Weekday::Sat,
+        rule: YearEndRule::NearestToMonthEnd,
+        leap_week: LeapWeekPlacement::LastPeriod,
+        naming: FiscalYearNaming::StartYear,
+    };
+
+    /// English: Create a retail calendar, panic when the year-end month is invalid
+    ///
+    /// 中文: 创建零售日历，年末月份无效时panic
+    pub fn new(
+        pattern: RetailPattern,
+        year_end_month: u32,
+        year_end_weekday: Weekday,
+        rule: YearEndRule,
+        leap_week: LeapWeekPlacement,
+        naming: FiscalYearNaming,
+    ) -> Self {
+        Self::try_new(
+            pattern,
+            year_end_month,
+            year_end_weekday,
+            rule,
+            leap_week,
+            naming,
+        )
+        .unwrap()
+    }
+
+    /// English: Create a retail calendar, return an error when the year-end month is invalid
+    ///
+    /// 中文: 创建零售日历，年末月份无效时返回错误
+    pub fn try_new(
+        pattern: RetailPattern,
+        year_end_month: u32,
+        year_end_weekday: Weekday,
+        rule: YearEndRule,
+        leap_week: LeapWeekPlacement,
+        naming: FiscalYearNaming,
+    ) -> Result<Self, DateUtilsError> {
+        if !(1..=12).contains(&year_end_month) {
+            return Err(DateUtilsError::InvalidDate);
+        }
+        Ok(RetailCalendar {
+            pattern,
+            year_end_month,
+            year_end_weekday,
+            rule,
+            leap_week,
+            naming,
+        })
+    }
+
+    /// English: Get the retail year of the date
+    ///
+    /// 中文: 获取日期所在的零售年
+    pub fn retail_year(&self, date: &NaiveDate) -> i32 {
+        let (first_day, last_day) = self.year_bounds(date);
+        match self.naming {
+            FiscalYearNaming::StartYear => first_day.year(),
+            FiscalYearNaming::EndYear => last_day.year(),
+        }
+    }
+
+    /// English: Get the retail quarter of the date
+    ///
+    /// 中文: 获取日期所在的零售季度
+    pub fn retail_quarter(&self, date: &NaiveDate) -> Quarter {
+        match self.locate(date, &self.quarter_weeks(date)).0 {
+            0 => Quarter::Q1,
+            1 => Quarter::Q2,
+            2 => Quarter::Q3,
+            _ => Quarter::Q4,
+        }
+    }
+
+    /// English: Get the retail period (month) of the date, from 1 to 12, or to 13 for the 13-period pattern
+    ///
+    /// 中文: 获取日期所在的零售时期（月），取值为1到12，13期模式下为1到13
+    pub fn retail_period(&self, date: &NaiveDate) -> u8 {
+        self.locate(date, &self.period_weeks(date)).0 as u8 + 1
+    }
+
+    /// English: Get the retail week of the year, from 1 to 53
+    ///
+    /// 中文: 获取日期是零售年的第几周，取值为1到53
+    pub fn retail_week(&self, date: &NaiveDate) -> u8 {
+        (self.week_index(date) + 1) as u8
+    }
+
+    /// English: Get the number of weeks in the retail year of the date, 52 or 53
+    ///
+    /// 中文: 获取日期所在零售年的周数，为52或53
+    pub fn weeks_in_retail_year(&self, date: &NaiveDate) -> u8 {
+        let (first_day, last_day) = self.year_bounds(date);
+        ((last_day - first_day).num_days() / 7 + 1) as u8
+    }
+
+    /// English: Get the first day of the retail year of the date
+    ///
+    /// 中文: 获取日期所在零售年的第一天
+    pub fn begin_of_retail_year(&self, date: &NaiveDate) -> NaiveDate {
+        self.year_bounds(date).0
+    }
+
+    /// English: Get the last day of the retail year of the date
+    ///
+    /// 中文: 获取日期所在零售年的最后一天
+    pub fn end_of_retail_year(&self, date: &NaiveDate) -> NaiveDate {
+        self.year_bounds(date).1
+    }
+
+    /// English: Get the first day of the retail quarter of the date
+    ///
+    /// 中文: 获取日期所在零售季度的第一天
+    pub fn begin_of_retail_quarter(&self, date: &NaiveDate) -> NaiveDate {
+        self.unit_bounds(date, &self.quarter_weeks(date)).0
+    }
+
+    /// English: Get the last day of the retail quarter of the date
+    ///
+    /// 中文: 获取日期所在零售季度的最后一天
+    pub fn end_of_retail_quarter(&self, date: &NaiveDate) -> NaiveDate {
+        self.unit_bounds(date, &self.quarter_weeks(date)).1
+    }
+
+    /// English: Get the first day of the retail period of the date
+    ///
+    /// 中文: 获取日期所在零售时期的第一天
+    pub fn begin_of_retail_period(&self, date: &NaiveDate) -> NaiveDate {
+        self.unit_bounds(date, &self.period_weeks(date)).0
+    }
+
+    /// English: Get the last day of the retail period of the date
+    ///
+    /// 中文: 获取日期所在零售时期的最后一天
+    pub fn end_of_retail_period(&self, date: &NaiveDate) -> NaiveDate {
+        self.unit_bounds(date, &self.period_weeks(date)).1
+    }
+
+    /// English: Get the first day of the retail week of the date
+    ///
+    /// 中文: 获取日期所在零售周的第一天
+    pub fn begin_of_retail_week(&self, date: &NaiveDate) -> NaiveDate {
+        date.begin_of_week_with(self.year_end_weekday.succ())
+    }
+
+    /// English: Get the last day of the retail week of the date
+    ///
+    /// 中文: 获取日期所在零售周的最后一天
+    pub fn end_of_retail_week(&self, date: &NaiveDate) -> NaiveDate {
+        self.begin_of_retail_week(date) + Days::new(6)
+    }
+
+    /// 以`year`年的年末月份确定的零售年的最后一天
+    fn year_end(&self, year: i32) -> NaiveDate {
+        let last_day = NaiveDate::from_ymd_opt(
+            year,
+            self.year_end_month,
+            days_in_month(year, self.year_end_month),
+        )
+        .unwrap();
+        // 以年末星期几开始的一周的第一天，即不晚于给定日期的最后一个年末星期几
+        match self.rule {
+            YearEndRule::LastWeekdayOfMonth => last_day.begin_of_week_with(self.year_end_weekday),
+            YearEndRule::NearestToMonthEnd => {
+                (last_day + Days::new(3)).begin_of_week_with(self.year_end_weekday)
+            }
+        }
+    }
+
+    /// 日期所在零售年的第一天和最后一天
+    fn year_bounds(&self, date: &NaiveDate) -> (NaiveDate, NaiveDate) {
+        let year = date.year();
+        [year - 1, year, year + 1]
+            .into_iter()
+            .map(|year| (self.year_end(year - 1) + Days::new(1), self.year_end(year)))
+            .find(|(first_day, last_day)| first_day <= date && date <= last_day)
+            .unwrap()
+    }
+
+    fn week_index(&self, date: &NaiveDate) -> i64 {
+        (*date - self.year_bounds(date).0).num_days() / 7
+    }
+
+    /// 将53周年份多出的一周加到第一个或最后一个单位上
+    fn with_leap_week(&self, date: &NaiveDate, mut weeks: Vec<i64>) -> Vec<i64> {
+        if self.weeks_in_retail_year(date) == 53 {
+            let index = match self.leap_week {
+                LeapWeekPlacement::FirstPeriod => 0,
+                LeapWeekPlacement::LastPeriod => weeks.len() - 1,
+            };
+            weeks[index] += 1;
+        }
+        weeks
+    }
+
+    /// 日期所在零售年中各个时期的周数
+    fn period_weeks(&self, date: &NaiveDate) -> Vec<i64> {
+        let quarter = match self.pattern {
+            RetailPattern::P445 => [4, 4, 5],
+            RetailPattern::P454 => [4, 5, 4],
+            RetailPattern::P544 => [5, 4, 4],
+            RetailPattern::Periods13 => return self.with_leap_week(date, vec![4; 13]),
+        };
+        self.with_leap_week(date, quarter.repeat(4))
+    }
+
+    /// 日期所在零售年中各个季度的周数
+    fn quarter_weeks(&self, date: &NaiveDate) -> Vec<i64> {
+        self.with_leap_week(date, vec![13; 4])
+    }
+
+    /// 日期所在的单位序号（从0开始）及该单位之前的周数
+    fn locate(&self, date: &NaiveDate, weeks: &[i64]) -> (usize, i64) {
+        let week = self.week_index(date);
+        let mut before = 0;
+        for (index, length) in weeks.iter().enumerate() {
+            if week < before + length {
+                return (index, before);
+            }
+            before += length;
+        }
+        unreachable!()
+    }
+
+    /// 日期所在单位的第一天和最后一天
+    fn unit_bounds(&self, date: &NaiveDate, weeks: &[i64]) -> (NaiveDate, NaiveDate) {
+        let (index, before) = self.locate(date, weeks);
+        let first_day = self.year_bounds(date).0 + Days::new(7 * before as u64);
+        let last_day = first_day + Days::new(7 * weeks[index] as u64 - 1);
+        (first_day, last_day)
+    }
+}
@@ -0,0 +1,589 @@
+use chrono::{
+    DateTime, Datelike, Days, Duration, MappedLocalTime, Months, NaiveDate, NaiveDateTime,
+    NaiveTime, TimeZone,
+};
+
+use crate::dst::{AmbiguityPolicy, GapPolicy, resolve_local};
+
+pub(crate) const NANOS_PER_MILLISECOND: i128 = 1_000_000;
+pub(crate) const NANOS_PER_SECOND: i128 = 1000 * NANOS_PER_MILLISECOND;
+pub(crate) const NANOS_PER_MINUTE: i128 = 60 * NANOS_PER_SECOND;
+pub(crate) const NANOS_PER_HOUR: i128 = 60 * NANOS_PER_MINUTE;
+pub(crate) const NANOS_PER_DAY: i128 = 24 * NANOS_PER_HOUR;
+pub(crate) const NANOS_PER_WEEK: i128 = 7 * NANOS_PER_DAY;
+
+/// English: The rounding method used by the `diff_*_with` helpers.
+///
+/// 中文: `diff_*_with`系列函数使用的取整方式
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// English: Round towards zero, which is the behavior of the `diff_*` helpers. Note that
+    /// `diff_months` and `diff_quarters` always return the absolute value, while the `diff_*_with`
+    /// helpers are negative when the date is before the other one.
+    ///
+    /// 中文: 向零取整，与`diff_*`系列函数的行为一致。注意`diff_months`和`diff_quarters`总是返回绝对值，
+    /// 而`diff_*_with`系列函数在当前日期早于另一个日期时为负数
+    #[default]
+    Trunc,
+    /// English: Round towards negative infinity.
+    ///
+    /// 中文: 向负无穷取整
+    Floor,
+    /// English: Round towards positive infinity.
+    ///
+    /// 中文: 向正无穷取整
+    Ceil,
+    /// English: Round to the nearest integer, and round half away from zero.
+    ///
+    /// 中文: 四舍五入，恰好为一半时远离零取整
+    Round,
+}
+
+impl Rounding {
+    /// 对`whole + remainder / unit`取整，其中`whole`是向零截断后的整数部分，`remainder`与`whole`同号
+    pub(crate) fn apply(self, whole: i64, remainder: i128, unit: i128) -> i64 {
+        match self {
+            Rounding::Trunc => whole,
+            Rounding::Floor if remainder < 0 => whole - 1,
+            Rounding::Ceil if remainder > 0 => whole + 1,
+            Rounding::Round if remainder.abs() * 2 >= unit => whole + remainder.signum() as i64,
+            _ => whole,
+        }
+    }
+}
+
+/// 以`whole + remainder / unit`的形式计算小数结果
+pub(crate) fn fraction(whole: i64, remainder: i128, unit: i128) -> f64 {
+    whole as f64 + remainder as f64 / unit as f64
+}
+
+/// 以纳秒表示时长
+pub(crate) fn nanos(duration: Duration) -> i128 {
+    duration.num_seconds() as i128 * NANOS_PER_SECOND + duration.subsec_nanos() as i128
+}
+
+/// 按照给定的单位拆分时长，返回向零截断的整数部分和余数
+pub(crate) fn split_duration(duration: Duration, unit: i128) -> (i64, i128) {
+    let nanos = nanos(duration);
+    ((nanos / unit) as i64, nanos % unit)
+}
+
+/// English: A calendar unit which a datetime can be rounded to.
+///
+/// 中文: 时间可以舍入到的日历单位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarUnit {
+    /// English: A calendar year
+    ///
+    /// 中文: 年
+    Year,
+    /// English: A calendar quarter
+    ///
+    /// 中文: 季度
+    Quarter,
+    /// English: A calendar month
+    ///
+    /// 中文: 月
+    Month,
+    /// English: An ISO week starting on Monday
+    ///
+    /// 中文: 从周一开始的ISO周
+    Week,
+    /// English: A day
+    ///
+    /// 中文: 日
+    Day,
+    /// English: An hour
+    ///
+    /// 中文: 小时
+    Hour,
+    /// English: A minute
+    ///
+    /// 中文: 分钟
+    Minute,
+    /// English: A second
+    ///
+    /// 中文: 秒
+    Second,
+}
+
+/// English: The helper of rounding a datetime to a step or a calendar unit, which generalizes
+/// `begin_of_second`, `begin_of_minute` and `begin_of_hour`. The steps are aligned to
+/// 1970-01-01 00:00:00 of the local time, and the halves are rounded up.
+///
+/// 中文: 将时间舍入到步长或日历单位的助手，是`begin_of_second`、`begin_of_minute`和`begin_of_hour`的推广。
+/// 步长以本地时间1970-01-01 00:00:00对齐，恰好为一半时向上舍入
+pub trait RoundingHelper: Sized {
+    /// English: Round to the nearest multiple of the step, panic when the step is not positive or
+    /// the result is out of range
+    ///
+    /// 中文: 舍入到最接近的步长整数倍，步长不是正数或结果超出范围时panic
+    fn round_to(&self, step: Duration) -> Self;
+
+    /// English: Round to the nearest multiple of the step, return None when the step is not
+    /// positive or the result is out of range
+    ///
+    /// 中文: 舍入到最接近的步长整数倍，步长不是正数或结果超出范围时返回None
+    fn round_to_opt(&self, step: Duration) -> Option<Self>;
+
+    /// English: Round down to a multiple of the step, panic when the step is not positive or the
+    /// result is out of range
+    ///
+    /// 中文: 向下舍入到步长的整数倍，步长不是正数或结果超出范围时panic
+    fn floor_to(&self, step: Duration) -> Self;
+
+    /// English: Round down to a multiple of the step, return None when the step is not positive
+    /// or the result is out of range
+    ///
+    /// 中文: 向下舍入到步长的整数倍，步长不是正数或结果超出范围时返回None
+    fn floor_to_opt(&self, step: Duration) -> Option<Self>;
+
+    /// English: Round up to a multiple of the step, panic when the step is not positive or the
+    /// result is out of range, e.g. `NaiveDateTime::MAX`
+    ///
+    /// 中文: 向上舍入到步长的整数倍，步长不是正数或结果超出范围（如`NaiveDateTime::MAX`）时panic
+    fn ceil_to(&self, step: Duration) -> Self;
+
+    /// English: Round up to a multiple of the step, return None when the step is not positive or
+    /// the result is out of range
+    ///
+    /// 中文: 向上舍入到步长的整数倍，步长不是正数或结果超出范围时返回None
+    fn ceil_to_opt(&self, step: Duration) -> Option<Self>;
+
+    /// English: Round to the nearer boundary of the calendar unit, e.g. May 16 12:00 rounds to
+    /// June 1, panic when the result is out of range
+    ///
+    /// 中文: 舍入到较近的日历单位边界，如5月16日12:00舍入为6月1日，结果超出范围时panic
+    fn round_to_unit(&self, unit: CalendarUnit) -> Self;
+
+    /// English: Round to the nearer boundary of the calendar unit, return None when the result is
+    /// out of range
+    ///
+    /// 中文: 舍入到较近的日历单位边界，结果超出范围时返回None
+    fn round_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self>;
+
+    /// English: Round down to the start of the calendar unit, panic when the result is out of range
+    ///
+    /// 中文: 向下舍入到日历单位的开始，结果超出范围时panic
+    fn floor_to_unit(&self, unit: CalendarUnit) -> Self;
+
+    /// English: Round down to the start of the calendar unit, return None when the result is out
+    /// of range
+    ///
+    /// 中文: 向下舍入到日历单位的开始，结果超出范围时返回None
+    fn floor_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self>;
+
+    /// English: Round up to the start of the next calendar unit, unless it is already at a
+    /// boundary, panic when the result is out of range
+    ///
+    /// 中文: 向上舍入到下一个日历单位的开始，已经处于边界时保持不变，结果超出范围时panic
+    fn ceil_to_unit(&self, unit: CalendarUnit) -> Self;
+
+    /// English: Round up to the start of the next calendar unit, unless it is already at a
+    /// boundary, return None when the result is out of range
+    ///
+    /// 中文: 向上舍入到下一个日历单位的开始，已经处于边界时保持不变，结果超出范围时返回None
+    fn ceil_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self>;
+}
+
+impl RoundingHelper for NaiveDateTime {
+    fn round_to(&self, step: Duration) -> Self {
+        self.round_to_opt(step).unwrap()
+    }
+
+    fn round_to_opt(&self, step: Duration) -> Option<Self> {
+        round_step(self, step, Rounding::Round)
+    }
+
+    fn floor_to(&self, step: Duration) -> Self {
+        self.floor_to_opt(step).unwrap()
+    }
+
+    fn floor_to_opt(&self, step: Duration) -> Option<Self> {
+        round_step(self, step, Rounding::Floor)
+    }
+
+    fn ceil_to(&self, step: Duration) -> Self {
+        self.ceil_to_opt(step).unwrap()
+    }
+
+    fn ceil_to_opt(&self, step: Duration) -> Option<Self> {
+        round_step(self, step, Rounding::Ceil)
+    }
+
+    fn round_to_unit(&self, unit: CalendarUnit) -> Self {
+        self.round_to_unit_opt(unit).unwrap()
+    }
+
+    fn round_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self> {
+        round_unit(self, unit, Rounding::Round)
+    }
+
+    fn floor_to_unit(&self, unit: CalendarUnit) -> Self {
+        self.floor_to_unit_opt(unit).unwrap()
+    }
+
+    fn floor_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self> {
+        round_unit(self, unit, Rounding::Floor)
+    }
+
+    fn ceil_to_unit(&self, unit: CalendarUnit) -> Self {
+        self.ceil_to_unit_opt(unit).unwrap()
+    }
+
+    fn ceil_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self> {
+        round_unit(self, unit, Rounding::Ceil)
+    }
+}
+
+/// 按照本地时间舍入，再转换回时区中的时间
+impl<Tz: TimeZone> RoundingHelper for DateTime<Tz> {
+    fn round_to(&self, step: Duration) -> Self {
+        self.round_to_opt(step).unwrap()
+    }
+
+    fn round_to_opt(&self, step: Duration) -> Option<Self> {
+        let local = round_step(&self.naive_local(), step, Rounding::Round)?;
+        to_zoned(self, &local, Rounding::Round)
+    }
+
+    fn floor_to(&self, step: Duration) -> Self {
+        self.floor_to_opt(step).unwrap()
+    }
+
+    fn floor_to_opt(&self, step: Duration) -> Option<Self> {
+        let local = round_step(&self.naive_local(), step, Rounding::Floor)?;
+        to_zoned(self, &local, Rounding::Floor)
+    }
+
+    fn ceil_to(&self, step: Duration) -> Self {
+        self.ceil_to_opt(step).unwrap()
+    }
+
+    fn ceil_to_opt(&self, step: Duration) -> Option<Self> {
+        let local = round_step(&self.naive_local(), step, Rounding::Ceil)?;
+        to_zoned(self, &local, Rounding::Ceil)
+    }
+
+    fn round_to_unit(&self, unit: CalendarUnit) -> Self {
+        self.round_to_unit_opt(unit).unwrap()
+    }
+
+    fn round_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self> {
+        let local = round_unit(&self.naive_local(), unit, Rounding::Round)?;
+        to_zoned(self, &local, Rounding::Round)
+    }
+
+    fn floor_to_unit(&self, unit: CalendarUnit) -> Self {
+        self.floor_to_unit_opt(unit).unwrap()
+    }
+
+    fn floor_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self> {
+        let local = round_unit(&self.naive_local(), unit, Rounding::Floor)?;
+        to_zoned(self, &local, Rounding::Floor)
+    }
+
+    fn ceil_to_unit(&self, unit: CalendarUnit) -> Self {
+        self.ceil_to_unit_opt(unit).unwrap()
+    }
+
+    fn ceil_to_unit_opt(&self, unit: CalendarUnit) -> Option<Self> {
+        let local = round_unit(&self.naive_local(), unit, Rounding::Ceil)?;
+        to_zoned(self, &local, Rounding::Ceil)
+    }
+}
+
+/// 按照步长舍入，步长以1970-01-01 00:00:00对齐，步长不是正数或结果超出范围时返回None
+fn round_step(time: &NaiveDateTime, step: Duration, rounding: Rounding) -> Option<NaiveDateTime> {
+    let step = nanos(step);
+    if step <= 0 {
+        return None;
+    }
+    let utc = time.and_utc();
+    let total = utc.timestamp() as i128 * NANOS_PER_SECOND + utc.timestamp_subsec_nanos() as i128;
+    let floor = total.div_euclid(step) * step;
+    let remainder = total - floor;
+    let result = match rounding {
+        Rounding::Ceil if remainder > 0 => floor.checked_add(step)?,
+        Rounding::Round if remainder * 2 >= step => floor.checked_add(step)?,
+        _ => floor,
+    };
+    let seconds = i64::try_from(result.div_euclid(NANOS_PER_SECOND)).ok()?;
+    DateTime::from_timestamp(seconds, result.rem_euclid(NANOS_PER_SECOND) as u32)
+        .map(|result| result.naive_utc())
+}
+
+/// 按照日历单位舍入，结果超出范围时返回None
+fn round_unit(
+    time: &NaiveDateTime,
+    unit: CalendarUnit,
+    rounding: Rounding,
+) -> Option<NaiveDateTime> {
+    let date = time.date();
+    let (floor, next) = match unit {
+        CalendarUnit::Year => {
+            let first = NaiveDate::from_ymd_opt(date.year(), 1, 1)?;
+            (first, first.checked_add_months(Months::new(12)))
+        }
+        CalendarUnit::Quarter => {
+            let month = date.month0() / 3 * 3 + 1;
+            let first = NaiveDate::from_ymd_opt(date.year(), month, 1)?;
+            (first, first.checked_add_months(Months::new(3)))
+        }
+        CalendarUnit::Month => {
+            let first = date.with_day(1)?;
+            (first, first.checked_add_months(Months::new(1)))
+        }
+        CalendarUnit::Week => {
+            let days = date.weekday().num_days_from_monday() as u64;
+            let first = date.checked_sub_days(Days::new(days))?;
+            (first, first.checked_add_days(Days::new(7)))
+        }
+        CalendarUnit::Day => (date, date.checked_add_days(Days::new(1))),
+        CalendarUnit::Hour => return round_step(time, Duration::hours(1), rounding),
+        CalendarUnit::Minute => return round_step(time, Duration::minutes(1), rounding),
+        CalendarUnit::Second => return round_step(time, Duration::seconds(1), rounding),
+    };
+    let floor = floor.and_time(NaiveTime::MIN);
+    if rounding == Rounding::Floor || *time == floor {
+        return Some(floor);
+    }
+    let next = next?.and_time(NaiveTime::MIN);
+    match rounding {
+        Rounding::Ceil => Some(next),
+        Rounding::Round if *time - floor >= next - *time => Some(next),
+        _ => Some(floor),
+    }
+}
+
+/// 将舍入后的本地时间转换回时区中的时间。出现两次的本地时间按照舍入方向选择，不存在的本地时间取变化的时刻
+fn to_zoned<Tz: TimeZone>(
+    time: &DateTime<Tz>,
+    local: &NaiveDateTime,
+    rounding: Rounding,
+) -> Option<DateTime<Tz>> {
+    let zone = time.timezone();
+    match zone.from_local_datetime(local) {
+        MappedLocalTime::Single(result) => Some(result),
+        MappedLocalTime::Ambiguous(earliest, latest) => {
+            let use_latest = match rounding {
+                Rounding::Floor => latest <= *time,
+                Rounding::Ceil => earliest < *time,
+                _ => {
+                    let distance =
+                        |other: &DateTime<Tz>| (other.naive_utc() - time.naive_utc()).abs();
+                    distance(&latest) < distance(&earliest)
+                }
+            };
+            Some(if use_latest { latest } else { earliest })
+        }
+        MappedLocalTime::None => resolve_local(
+            &zone,
+            local,
+            GapPolicy::ShiftForward,
+            AmbiguityPolicy::Earliest,
+        )
+        .ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tz::PosixTz;
+    use chrono::{FixedOffset, Timelike};
+
+    fn calc_datetime(month: u32, day: u32, hour: u32, minute: u32, second: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, second)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_round_to_step() {
+        let time = calc_datetime(5, 16, 10, 7, 30);
+        let quarter = Duration::minutes(15);
+        assert_eq!(time.round_to(quarter), calc_datetime(5, 16, 10, 15, 0));
+        assert_eq!(time.floor_to(quarter), calc_datetime(5, 16, 10, 0, 0));
+        assert_eq!(time.ceil_to(quarter), calc_datetime(5, 16, 10, 15, 0));
+        let time = calc_datetime(5, 16, 10, 7, 29);
+        assert_eq!(time.round_to(quarter), calc_datetime(5, 16, 10, 0, 0));
+        let time = calc_datetime(5, 16, 10, 5, 0);
+        assert_eq!(time.floor_to(Duration::minutes(5)), time);
+        assert_eq!(time.ceil_to(Duration::minutes(5)), time);
+        assert_eq!(
+            calc_datetime(12, 31, 23, 58, 0).ceil_to(Duration::minutes(5)),
+            NaiveDate::from_ymd_opt(2025, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+        let before_epoch = NaiveDate::from_ymd_opt(1969, 12, 31)
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap();
+        assert_eq!(
+            before_epoch.floor_to(Duration::minutes(1)),
+            before_epoch.with_second(0).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_round_to_zero_step() {
+        calc_datetime(5, 16, 10, 7, 30).round_to(Duration::zero());
+    }
+
+    #[test]
+    fn test_round_opt_out_of_range() {
+        let time = NaiveDate::MAX.and_hms_opt(23, 50, 0).unwrap();
+        let hour = Duration::hours(1);
+        assert_eq!(time.ceil_to_opt(hour), None);
+        assert_eq!(time.round_to_opt(hour), None);
+        assert_eq!(time.floor_to_opt(hour), time.with_minute(0));
+        assert_eq!(time.ceil_to_unit_opt(CalendarUnit::Day), None);
+        assert_eq!(
+            time.floor_to_unit_opt(CalendarUnit::Month),
+            Some(NaiveDate::MAX.with_day(1).unwrap().and_time(NaiveTime::MIN))
+        );
+        let time = NaiveDate::MIN.and_time(NaiveTime::MIN);
+        assert_eq!(time.floor_to_unit_opt(CalendarUnit::Day), Some(time));
+        assert_eq!(time.round_to_opt(Duration::zero()), None);
+        assert_eq!(time.floor_to_opt(Duration::seconds(-1)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ceil_to_out_of_range() {
+        NaiveDate::MAX
+            .and_hms_opt(23, 50, 0)
+            .unwrap()
+            .ceil_to(Duration::hours(1));
+    }
+
+    #[test]
+    fn test_round_to_unit() {
+        let time = calc_datetime(5, 16, 12, 0, 0);
+        assert_eq!(
+            time.round_to_unit(CalendarUnit::Month),
+            calc_datetime(6, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            time.floor_to_unit(CalendarUnit::Month),
+            calc_datetime(5, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            time.ceil_to_unit(CalendarUnit::Month),
+            calc_datetime(6, 1, 0, 0, 0)
+        );
+        let time = calc_datetime(5, 16, 11, 0, 0);
+        assert_eq!(
+            time.round_to_unit(CalendarUnit::Month),
+            calc_datetime(5, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            time.round_to_unit(CalendarUnit::Quarter),
+            calc_datetime(4, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            time.floor_to_unit(CalendarUnit::Year),
+            calc_datetime(1, 1, 0, 0, 0)
+        );
+        // 2024年5月16日是周四
+        assert_eq!(
+            time.floor_to_unit(CalendarUnit::Week),
+            calc_datetime(5, 13, 0, 0, 0)
+        );
+        assert_eq!(
+            time.round_to_unit(CalendarUnit::Week),
+            calc_datetime(5, 13, 0, 0, 0)
+        );
+        let noon = calc_datetime(5, 16, 12, 0, 0);
+        assert_eq!(
+            noon.round_to_unit(CalendarUnit::Week),
+            calc_datetime(5, 20, 0, 0, 0)
+        );
+        assert_eq!(
+            time.round_to_unit(CalendarUnit::Day),
+            calc_datetime(5, 16, 0, 0, 0)
+        );
+        let time = calc_datetime(5, 16, 11, 29, 30);
+        assert_eq!(
+            time.round_to_unit(CalendarUnit::Hour),
+            calc_datetime(5, 16, 11, 0, 0)
+        );
+        assert_eq!(
+            time.round_to_unit(CalendarUnit::Minute),
+            calc_datetime(5, 16, 11, 30, 0)
+        );
+        assert_eq!(time.ceil_to_unit(CalendarUnit::Second), time);
+        let first = calc_datetime(6, 1, 0, 0, 0);
+        assert_eq!(first.ceil_to_unit(CalendarUnit::Month), first);
+    }
+
+    #[test]
+    fn test_round_zoned() {
+        let zone: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+        let at = |month, day, hour, minute| {
+            zone.from_local_datetime(&calc_datetime(month, day, hour, minute, 0))
+                .latest()
+                .unwrap()
+        };
+        // 尼泊尔的偏移量为+05:45，按照本地时间对齐
+        let nepal = FixedOffset::east_opt(5 * 3600 + 45 * 60).unwrap();
+        let time = nepal
+            .from_local_datetime(&calc_datetime(5, 16, 10, 7, 0))
+            .unwrap();
+        let floor = time.floor_to(Duration::minutes(15));
+        assert_eq!(floor.naive_local(), calc_datetime(5, 16, 10, 0, 0));
+
+        // 11月3日01:00到02:00出现两次，第二次的01:50向下舍入为第二次的01:00
+        let time = at(11, 3, 1, 50);
+        let floor = time.floor_to(Duration::hours(1));
+        assert_eq!(floor.naive_local(), calc_datetime(11, 3, 1, 0, 0));
+        assert_eq!(time.naive_utc() - floor.naive_utc(), Duration::minutes(50));
+        let ceil = at(11, 3, 0, 50).ceil_to(Duration::hours(1));
+        assert_eq!(ceil.naive_utc(), calc_datetime(11, 3, 5, 0, 0));
+
+        // 3月10日02:00到03:00不存在，向上舍入到变化的时刻
+        let ceil = at(3, 10, 1, 50).ceil_to(Duration::hours(1));
+        assert_eq!(ceil.naive_local(), calc_datetime(3, 10, 3, 0, 0));
+        let month = at(3, 10, 12, 0).round_to_unit(CalendarUnit::Month);
+        assert_eq!(month.naive_local(), calc_datetime(3, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_apply_positive() {
+        assert_eq!(Rounding::Trunc.apply(2, 1, 2), 2);
+        assert_eq!(Rounding::Floor.apply(2, 1, 2), 2);
+        assert_eq!(Rounding::Ceil.apply(2, 1, 2), 3);
+        assert_eq!(Rounding::Round.apply(2, 1, 2), 3);
+        assert_eq!(Rounding::Round.apply(2, 1, 3), 2);
+        assert_eq!(Rounding::Ceil.apply(2, 0, 3), 2);
+    }
+
+    #[test]
+    fn test_apply_negative() {
+        assert_eq!(Rounding::Trunc.apply(-2, -1, 2), -2);
+        assert_eq!(Rounding::Floor.apply(-2, -1, 2), -3);
+        assert_eq!(Rounding::Ceil.apply(-2, -1, 2), -2);
+        assert_eq!(Rounding::Round.apply(-2, -1, 2), -3);
+        assert_eq!(Rounding::Floor.apply(0, -1, 3), -1);
+    }
+
+    #[test]
+    fn test_split_duration() {
+        let duration = Duration::hours(36);
+        assert_eq!(
+            split_duration(duration, NANOS_PER_DAY),
+            (1, NANOS_PER_DAY / 2)
+        );
+        let duration = Duration::hours(-36);
+        assert_eq!(
+            split_duration(duration, NANOS_PER_DAY),
+            (-1, -NANOS_PER_DAY / 2)
+        );
+        assert_eq!(fraction(-1, -NANOS_PER_DAY / 2, NANOS_PER_DAY), -1.5);
+    }
+}
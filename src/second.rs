@@ -2,6 +2,8 @@ use chrono::Duration;
 use chrono::NaiveDateTime;
 use chrono::Timelike;
 
+use crate::error::DateUtilsError;
+
 /// English: SecondHelper
 ///
 /// 中文：秒助手
@@ -27,6 +29,26 @@ pub trait SecondHelper {
     fn sub_seconds_opt(&self, second: i64) -> Option<Self>
     where
         Self: Sized;
+    /// English: Add the specified number of seconds, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 加上指定的秒数，溢出时返回错误而不是panic
+    fn try_add_seconds(&self, second: i64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.add_seconds_opt(second)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Subtract the specified number of seconds, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 减去指定的秒数，溢出时返回错误而不是panic
+    fn try_sub_seconds(&self, second: i64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.sub_seconds_opt(second)
+            .ok_or(DateUtilsError::OutOfRange)
+    }
     fn diff_seconds(&self, other: &Self) -> i64;
     fn begin_of_second(&self) -> Self;
 
@@ -0,0 +1,297 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{Duration, NaiveTime, Timelike};
+
+use crate::rounding::{NANOS_PER_DAY, NANOS_PER_MINUTE, NANOS_PER_SECOND};
+
+/// 一天的分钟数
+const MINUTES_PER_DAY: i64 = (NANOS_PER_DAY / NANOS_PER_MINUTE) as i64;
+
+/// English: The helper of a time of day, where the arithmetic wraps around midnight
+///
+/// 中文: 一天中时间的助手，运算会跨越午夜循环
+pub trait TimeOfDayHelper {
+    /// English: Add minutes and wrap around midnight, e.g. 23:30 + 60 minutes = 00:30
+    ///
+    /// 中文: 添加分钟数并跨越午夜循环，如23:30加60分钟为00:30
+    fn add_minutes_wrapping(&self, n: i64) -> Self;
+
+    /// English: Get the number of whole minutes from the other time forward to this time on the
+    /// clock, from 0 to 1439, e.g. 01:00 is 120 minutes after 23:00
+    ///
+    /// 中文: 在时钟上从另一个时间向后到当前时间的整分钟数，取值为0到1439，如01:00在23:00之后120分钟
+    fn diff_minutes_wrapping(&self, other: &Self) -> i64;
+
+    /// English: Get the start of the hour
+    ///
+    /// 中文: 获取所在小时的开始时间
+    fn begin_of_hour(&self) -> Self;
+
+    /// English: Get the end of the hour, such as 10:59:59
+    ///
+    /// 中文: 获取所在小时的结束时间，如10:59:59
+    fn end_of_hour(&self) -> Self;
+
+    /// English: Whether the time is before noon
+    ///
+    /// 中文: 是否是上午（中午12点之前）
+    fn is_am(&self) -> bool;
+
+    /// English: Whether the time is noon or later
+    ///
+    /// 中文: 是否是下午（中午12点及之后）
+    fn is_pm(&self) -> bool;
+
+    /// English: Get the part of the day by the default boundaries
+    ///
+    /// 中文: 按照默认的分界获取所在的时段
+    fn day_part(&self) -> DayPart;
+
+    /// English: Get the part of the day by the given boundaries
+    ///
+    /// 中文: 按照给定的分界获取所在的时段
+    fn day_part_with(&self, boundaries: &DayPartBoundaries) -> DayPart;
+
+    /// English: Round to the nearest multiple of the step since midnight, rounding half up and
+    /// wrapping around midnight. Panic when the step is not positive or does not fit in `i64`
+    /// nanoseconds.
+    ///
+    /// 中文: 舍入到从午夜开始的最接近的步长整数倍，恰好为一半时向上舍入并跨越午夜循环。
+    /// 步长不是正数或超出`i64`纳秒的范围时panic
+    fn round_to(&self, step: Duration) -> Self;
+
+    /// English: Round to the nearest multiple of the step since midnight like `round_to`, return
+    /// `None` when the step is not positive or does not fit in `i64` nanoseconds
+    ///
+    /// 中文: 与`round_to`相同地舍入到从午夜开始的最接近的步长整数倍，
+    /// 步长不是正数或超出`i64`纳秒的范围时返回`None`
+    fn round_to_opt(&self, step: Duration) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+impl TimeOfDayHelper for NaiveTime {
+    fn add_minutes_wrapping(&self, n: i64) -> Self {
+        let minutes = n.rem_euclid(MINUTES_PER_DAY);
+        self.overflowing_add_signed(Duration::minutes(minutes)).0
+    }
+
+    fn diff_minutes_wrapping(&self, other: &Self) -> i64 {
+        let seconds =
+            self.num_seconds_from_midnight() as i64 - other.num_seconds_from_midnight() as i64;
+        seconds.rem_euclid(MINUTES_PER_DAY * 60) / 60
+    }
+
+    fn begin_of_hour(&self) -> Self {
+        NaiveTime::from_hms_opt(self.hour(), 0, 0).unwrap()
+    }
+
+    fn end_of_hour(&self) -> Self {
+        NaiveTime::from_hms_opt(self.hour(), 59, 59).unwrap()
+    }
+
+    fn is_am(&self) -> bool {
+        self.hour() < 12
+    }
+
+    fn is_pm(&self) -> bool {
+        !self.is_am()
+    }
+
+    fn day_part(&self) -> DayPart {
+        self.day_part_with(&DayPartBoundaries::default())
+    }
+
+    fn day_part_with(&self, boundaries: &DayPartBoundaries) -> DayPart {
+        // 取最近一个已经开始的时段
+        [
+            (boundaries.morning, DayPart::Morning),
+            (boundaries.afternoon, DayPart::Afternoon),
+            (boundaries.evening, DayPart::Evening),
+            (boundaries.night, DayPart::Night),
+        ]
+        .into_iter()
+        .min_by_key(|(start, _)| self.diff_minutes_wrapping(start))
+        .map(|(_, part)| part)
+        .unwrap()
+    }
+
+    fn round_to(&self, step: Duration) -> Self {
+        self.round_to_opt(step).unwrap()
+    }
+
+    fn round_to_opt(&self, step: Duration) -> Option<Self> {
+        let step = step.num_nanoseconds().filter(|step| *step > 0)? as i128;
+        let nanos = self.num_seconds_from_midnight() as i128 * NANOS_PER_SECOND
+            + self.nanosecond().min(999_999_999) as i128;
+        let rounded = (nanos + step / 2) / step * step % NANOS_PER_DAY;
+        NaiveTime::from_num_seconds_from_midnight_opt(
+            (rounded / NANOS_PER_SECOND) as u32,
+            (rounded % NANOS_PER_SECOND) as u32,
+        )
+    }
+}
+
+/// English: A part of the day
+///
+/// 中文: 一天中的时段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayPart {
+    /// English: Morning
+    ///
+    /// 中文: 上午
+    Morning,
+    /// English: Afternoon
+    ///
+    /// 中文: 下午
+    Afternoon,
+    /// English: Evening
+    ///
+    /// 中文: 晚上
+    Evening,
+    /// English: Night
+    ///
+    /// 中文: 夜间
+    Night,
+}
+
+impl Display for DayPart {
+    /// 默认输出英文名称，使用`{:#}`时输出中文名称
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (english, chinese) = match self {
+            DayPart::Morning => ("morning", "上午"),
+            DayPart::Afternoon => ("afternoon", "下午"),
+            DayPart::Evening => ("evening", "晚上"),
+            DayPart::Night => ("night", "夜间"),
+        };
+        write!(f, "{}", if f.alternate() { chinese } else { english })
+    }
+}
+
+/// English: The start times of the parts of the day. Each part lasts until the next one starts,
+/// and the night lasts past midnight until the morning. The default is 05:00, 12:00, 17:00 and 21:00.
+///
+/// 中文: 一天中各个时段的开始时间。每个时段持续到下一个时段开始，夜间跨越午夜持续到上午。
+/// 默认为05:00、12:00、17:00和21:00
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DayPartBoundaries {
+    morning: NaiveTime,
+    afternoon: NaiveTime,
+    evening: NaiveTime,
+    night: NaiveTime,
+}
+
+impl DayPartBoundaries {
+    /// English: Create the boundaries by the start times of the morning, afternoon, evening and night
+    ///
+    /// 中文: 以上午、下午、晚上和夜间的开始时间创建分界
+    pub fn new(
+        morning: NaiveTime,
+        afternoon: NaiveTime,
+        evening: NaiveTime,
+        night: NaiveTime,
+    ) -> Self {
+        DayPartBoundaries {
+            morning,
+            afternoon,
+            evening,
+            night,
+        }
+    }
+
+    /// English: Get the start time of the part of the day
+    ///
+    /// 中文: 获取某个时段的开始时间
+    pub fn start_of(&self, part: DayPart) -> NaiveTime {
+        match part {
+            DayPart::Morning => self.morning,
+            DayPart::Afternoon => self.afternoon,
+            DayPart::Evening => self.evening,
+            DayPart::Night => self.night,
+        }
+    }
+}
+
+impl Default for DayPartBoundaries {
+    fn default() -> Self {
+        let hour = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        DayPartBoundaries::new(hour(5), hour(12), hour(17), hour(21))
+    }
+}
+
+/// English: A range of the time of day from the start (inclusive) to the end (exclusive), which
+/// crosses midnight when the end is before the start, such as 22:00-06:00. A range whose start
+/// and end are equal covers the whole day.
+///
+/// 中文: 一天中从开始时间（包含）到结束时间（不包含）的时间范围，结束时间早于开始时间时跨越午夜，如22:00-06:00。
+/// 开始时间与结束时间相同时表示一整天
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeOfDayRange {
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl TimeOfDayRange {
+    /// English: Create the range
+    ///
+    /// 中文: 创建时间范围
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        TimeOfDayRange { start, end }
+    }
+
+    /// English: Get the start time
+    ///
+    /// 中文: 获取开始时间
+    pub fn start(&self) -> NaiveTime {
+        self.start
+    }
+
+    /// English: Get the end time
+    ///
+    /// 中文: 获取结束时间
+    pub fn end(&self) -> NaiveTime {
+        self.end
+    }
+
+    /// English: Whether the range crosses midnight
+    ///
+    /// 中文: 是否跨越午夜
+    pub fn crosses_midnight(&self) -> bool {
+        self.end <= self.start && self.end != NaiveTime::MIN
+    }
+
+    /// English: Get the length of the range
+    ///
+    /// 中文: 获取时间范围的长度
+    pub fn duration(&self) -> Duration {
+        let length = self.end - self.start;
+        if length > Duration::zero() {
+            length
+        } else {
+            length + Duration::days(1)
+        }
+    }
+
+    /// English: Whether the time is in the range
+    ///
+    /// 中文: 时间是否在范围内
+    pub fn contains(&self, time: &NaiveTime) -> bool {
+        if self.start < self.end {
+            self.start <= *time && *time < self.end
+        } else {
+            self.start <= *time || *time < self.end
+        }
+    }
+}
+
+impl Display for TimeOfDayRange {
+    /// 输出`22:00-06:00`的形式
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{}",
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M")
+        )
+    }
+}
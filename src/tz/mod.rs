@@ -0,0 +1,219 @@
+use chrono::{DateTime, Duration, FixedOffset, MappedLocalTime, NaiveDateTime, Offset, TimeZone};
+
+mod posix;
+mod zoneinfo;
+
+pub use posix::{PosixOffset, PosixTz};
+pub use zoneinfo::{ZoneInfo, ZoneInfoOffset};
+
+/// English: A change of the UTC offset of a time zone, such as the start or the end of daylight
+/// saving time.
+///
+/// 中文: 时区UTC偏移量的一次变化，如夏令时的开始或结束
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transition {
+    /// English: The UTC time at which the new offset takes effect
+    ///
+    /// 中文: 新偏移量开始生效的UTC时间
+    pub utc: NaiveDateTime,
+    /// English: The offset before the transition
+    ///
+    /// 中文: 变化之前的偏移量
+    pub before: FixedOffset,
+    /// English: The offset after the transition
+    ///
+    /// 中文: 变化之后的偏移量
+    pub after: FixedOffset,
+}
+
+impl Transition {
+    /// English: The local time just before the transition, by the old offset
+    ///
+    /// 中文: 按照旧偏移量，变化发生时的本地时间
+    pub fn local_before(&self) -> NaiveDateTime {
+        self.utc + self.before
+    }
+
+    /// English: The local time just after the transition, by the new offset
+    ///
+    /// 中文: 按照新偏移量，变化发生时的本地时间
+    pub fn local_after(&self) -> NaiveDateTime {
+        self.utc + self.after
+    }
+
+    /// English: Whether the clocks move forward, which skips some local times
+    ///
+    /// 中文: 时钟是否向前调整，此时部分本地时间不存在
+    pub fn is_gap(&self) -> bool {
+        self.after.local_minus_utc() > self.before.local_minus_utc()
+    }
+
+    /// English: Whether the clocks move backward, which repeats some local times
+    ///
+    /// 中文: 时钟是否向后调整，此时部分本地时间会出现两次
+    pub fn is_overlap(&self) -> bool {
+        self.after.local_minus_utc() < self.before.local_minus_utc()
+    }
+}
+
+/// 搜索偏移量变化时的采样间隔，假设相邻两次变化的间隔超过该值
+const SEARCH_STEP_HOURS: i64 = 6;
+
+/// English: Find the transitions of the time zone between the two times (inclusive), in UTC order.
+/// The offsets are sampled every few hours and each change is located to the second with a binary
+/// search, so two changes closer than the sampling step may be missed.
+///
+/// 中文: 查找两个时间之间（包含两端）时区的偏移量变化，按照UTC时间排序。每隔几个小时采样一次偏移量，
+/// 再通过二分查找精确到秒，因此间隔小于采样间隔的两次变化可能被遗漏
+pub fn transitions_between<Tz: TimeZone, T: TimeZone>(
+    zone: &Tz,
+    start: &DateTime<T>,
+    end: &DateTime<T>,
+) -> Vec<Transition> {
+    let offset_at = |utc: &NaiveDateTime| zone.offset_from_utc_datetime(utc).fix();
+    let end = end.naive_utc();
+    let step = Duration::hours(SEARCH_STEP_HOURS);
+    let mut transitions = vec![];
+    let start = start.naive_utc();
+    // 从开始时间的前一秒采样，使恰好发生在开始时间的变化也能被找到
+    let mut low = start
+        .checked_sub_signed(Duration::seconds(1))
+        .unwrap_or(start);
+    let mut before = offset_at(&low);
+    while low < end {
+        let high = low
+            .checked_add_signed(step)
+            .map_or(end, |high| high.min(end));
+        let after = offset_at(&high);
+        if after != before {
+            let transition = locate(&offset_at, low, high, before);
+            if transition.utc >= start {
+                transitions.push(transition);
+            }
+        }
+        low = high;
+        before = after;
+    }
+    transitions
+}
+
+/// 在偏移量为`before`的`low`和偏移量不同的`high`之间二分查找第一个偏移量变化的秒
+fn locate(
+    offset_at: &impl Fn(&NaiveDateTime) -> FixedOffset,
+    mut low: NaiveDateTime,
+    mut high: NaiveDateTime,
+    before: FixedOffset,
+) -> Transition {
+    while high - low > Duration::seconds(1) {
+        let middle = low + (high - low) / 2;
+        if offset_at(&middle) == before {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    // 变化发生在整秒，不是整秒时取变化之后的第一个整秒
+    let whole = high - Duration::nanoseconds(high.and_utc().timestamp_subsec_nanos() as i64);
+    let utc = if whole > low && offset_at(&whole) != before {
+        whole
+    } else {
+        high
+    };
+    Transition {
+        utc,
+        before,
+        after: offset_at(&utc),
+    }
+}
+
+/// English: Whether the local time occurs twice in the time zone, such as 01:30 on the day daylight
+/// saving time ends
+///
+/// 中文: 本地时间在时区中是否出现两次，如夏令时结束当天的01:30
+pub fn is_ambiguous_local<Tz: TimeZone>(zone: &Tz, local: &NaiveDateTime) -> bool {
+    matches!(
+        zone.from_local_datetime(local),
+        MappedLocalTime::Ambiguous(_, _)
+    )
+}
+
+/// English: Whether the local time is skipped in the time zone, such as 02:30 on the day daylight
+/// saving time starts
+///
+/// 中文: 本地时间在时区中是否不存在，如夏令时开始当天的02:30
+pub fn is_skipped_local<Tz: TimeZone>(zone: &Tz, local: &NaiveDateTime) -> bool {
+    matches!(zone.from_local_datetime(local), MappedLocalTime::None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Utc};
+
+    fn datetime(month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn utc(month: u32, day: u32) -> DateTime<Utc> {
+        datetime(month, day, 0, 0).and_utc()
+    }
+
+    #[test]
+    fn test_transitions_between() {
+        let zone: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+        let transitions = transitions_between(&zone, &utc(1, 1), &utc(12, 31));
+        assert_eq!(transitions, zone.transitions(2024));
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].utc, datetime(3, 10, 7, 0));
+        assert!(transitions[0].is_gap());
+        assert_eq!(
+            transitions[0].before,
+            FixedOffset::west_opt(5 * 3600).unwrap()
+        );
+        assert_eq!(transitions[1].utc, datetime(11, 3, 6, 0));
+        assert!(transitions[1].is_overlap());
+
+        assert!(transitions_between(&zone, &utc(4, 1), &utc(10, 1)).is_empty());
+        let fixed = FixedOffset::east_opt(8 * 3600).unwrap();
+        assert!(transitions_between(&fixed, &utc(1, 1), &utc(12, 31)).is_empty());
+    }
+
+    #[test]
+    fn test_transitions_between_boundary() {
+        let zone: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+        let at = datetime(3, 10, 7, 0).and_utc();
+        let hour = Duration::hours(1);
+        let starting = transitions_between(&zone, &at, &(at + hour));
+        assert_eq!(starting.len(), 1);
+        assert_eq!(starting[0].utc, datetime(3, 10, 7, 0));
+        let ending = transitions_between(&zone, &(at - hour), &at);
+        assert_eq!(ending, starting);
+        let after = at + Duration::seconds(1);
+        assert!(transitions_between(&zone, &after, &(after + hour)).is_empty());
+        assert_eq!(transitions_between(&zone, &at, &at), starting);
+    }
+
+    #[test]
+    fn test_transitions_between_sub_hour() {
+        // 豪勋爵岛的夏令时只调整30分钟
+        let zone: PosixTz = "<+1030>-10:30<+11>-11,M10.1.0,M4.1.0".parse().unwrap();
+        let transitions = transitions_between(&zone, &utc(1, 1), &utc(12, 31));
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].local_before(), datetime(4, 7, 2, 0));
+        assert_eq!(transitions[1].local_before(), datetime(10, 6, 2, 0));
+    }
+
+    #[test]
+    fn test_ambiguous_and_skipped() {
+        let zone: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+        assert!(is_skipped_local(&zone, &datetime(3, 10, 2, 30)));
+        assert!(!is_skipped_local(&zone, &datetime(3, 10, 3, 0)));
+        assert!(!is_ambiguous_local(&zone, &datetime(3, 10, 2, 30)));
+        assert!(is_ambiguous_local(&zone, &datetime(11, 3, 1, 30)));
+        assert!(!is_ambiguous_local(&zone, &datetime(11, 3, 2, 0)));
+        assert!(!is_skipped_local(&Utc, &datetime(3, 10, 2, 30)));
+    }
+}
@@ -0,0 +1,562 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use chrono::{
+    Datelike, Days, Duration, FixedOffset, MappedLocalTime, NaiveDate, NaiveDateTime, Offset,
+    TimeZone, Weekday,
+};
+
+use crate::error::DateUtilsError;
+use crate::now::LocalZone;
+use crate::tz::Transition;
+use crate::week::WeekHelper;
+
+/// English: A time zone described by a POSIX `TZ` rule string, such as `CST6CDT,M3.2.0,M11.1.0`
+/// or `<+0530>-5:30`. Note that POSIX offsets are west of UTC, so `CST6` is UTC-06:00. When the
+/// daylight saving rules are omitted, the US rules `M3.2.0,M11.1.0` are used like glibc does.
+///
+/// 中文: 由POSIX `TZ`规则字符串描述的时区，如`CST6CDT,M3.2.0,M11.1.0`或`<+0530>-5:30`。
+/// 注意POSIX的偏移量以UTC以西为正，所以`CST6`为UTC-06:00。省略夏令时规则时，与glibc一样使用美国的规则`M3.2.0,M11.1.0`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PosixTz {
+    std_name: String,
+    std_offset: i32,
+    dst: Option<Dst>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Dst {
+    name: String,
+    offset: i32,
+    start: Rule,
+    end: Rule,
+}
+
+/// 夏令时开始或结束的日期和当地时间（秒数）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Rule {
+    date: RuleDate,
+    time: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RuleDate {
+    /// `Jn`，1到365，不计算2月29日
+    Julian1(u16),
+    /// `n`，0到365，计算2月29日
+    Julian0(u16),
+    /// `Mm.w.d`，第`m`月第`w`个星期`d`，`w`为5时表示最后一个
+    MonthWeekDay(u32, i8, Weekday),
+}
+
+/// English: The offset of a [`PosixTz`] at some time, together with its abbreviation
+///
+/// 中文: [`PosixTz`]在某个时间的偏移量，以及对应的时区缩写
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosixOffset {
+    tz: PosixTz,
+    is_dst: bool,
+}
+
+impl PosixOffset {
+    /// English: Whether daylight saving time is in effect
+    ///
+    /// 中文: 是否处于夏令时
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// English: The abbreviation of the offset, such as `CST` or `CDT`
+    ///
+    /// 中文: 偏移量的缩写，如`CST`或`CDT`
+    pub fn abbreviation(&self) -> &str {
+        match (&self.tz.dst, self.is_dst) {
+            (Some(dst), true) => &dst.name,
+            _ => &self.tz.std_name,
+        }
+    }
+}
+
+impl Offset for PosixOffset {
+    fn fix(&self) -> FixedOffset {
+        let seconds = match (&self.tz.dst, self.is_dst) {
+            (Some(dst), true) => dst.offset,
+            _ => self.tz.std_offset,
+        };
+        FixedOffset::east_opt(seconds).unwrap()
+    }
+}
+
+impl Display for PosixOffset {
+    /// 输出时区缩写
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl PosixTz {
+    /// English: The abbreviation of the standard time
+    ///
+    /// 中文: 标准时间的缩写
+    pub fn std_name(&self) -> &str {
+        &self.std_name
+    }
+
+    /// English: The offset of the standard time
+    ///
+    /// 中文: 标准时间的偏移量
+    pub fn std_offset(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.std_offset).unwrap()
+    }
+
+    /// English: The abbreviation of daylight saving time, `None` when there is no daylight saving time
+    ///
+    /// 中文: 夏令时的缩写，没有夏令时时为`None`
+    pub fn dst_name(&self) -> Option<&str> {
+        self.dst.as_ref().map(|dst| dst.name.as_str())
+    }
+
+    /// English: The offset of daylight saving time, `None` when there is no daylight saving time
+    ///
+    /// 中文: 夏令时的偏移量，没有夏令时时为`None`
+    pub fn dst_offset(&self) -> Option<FixedOffset> {
+        self.dst
+            .as_ref()
+            .map(|dst| FixedOffset::east_opt(dst.offset).unwrap())
+    }
+
+    /// English: Get the transitions which take place in the year, in UTC order
+    ///
+    /// 中文: 获取某一年中发生的偏移量变化，按照UTC时间排序
+    pub fn transitions(&self, year: i32) -> Vec<Transition> {
+        let Some(dst) = &self.dst else {
+            return vec![];
+        };
+        let std_offset = self.std_offset();
+        let dst_offset = self.dst_offset().unwrap();
+        let mut transitions: Vec<Transition> = [year - 1, year, year + 1]
+            .into_iter()
+            .filter_map(|year| self.dst_bounds(dst, year))
+            .flat_map(|(start, end)| {
+                [
+                    Transition {
+                        utc: start,
+                        before: std_offset,
+                        after: dst_offset,
+                    },
+                    Transition {
+                        utc: end,
+                        before: dst_offset,
+                        after: std_offset,
+                    },
+                ]
+            })
+            .filter(|transition| transition.local_before().year() == year)
+            .collect();
+        transitions.sort_by_key(|transition| transition.utc);
+        transitions
+    }
+
+    /// 某一年夏令时开始和结束的UTC时间，开始时间按照标准时间计算，结束时间按照夏令时计算
+    fn dst_bounds(&self, dst: &Dst, year: i32) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let start = dst.start.local(year)? - Duration::seconds(self.std_offset as i64);
+        let end = dst.end.local(year)? - Duration::seconds(dst.offset as i64);
+        Some((start, end))
+    }
+
+    fn is_dst_at(&self, utc: &NaiveDateTime) -> bool {
+        let Some(dst) = &self.dst else {
+            return false;
+        };
+        let year = (*utc + Duration::seconds(self.std_offset as i64)).year();
+        match self.dst_bounds(dst, year) {
+            // 北半球，夏令时在一年之中
+            Some((start, end)) if start <= end => start <= *utc && *utc < end,
+            // 南半球，夏令时跨越新年
+            Some((start, end)) => !(end <= *utc && *utc < start),
+            None => false,
+        }
+    }
+
+    fn offset(&self, is_dst: bool) -> PosixOffset {
+        PosixOffset {
+            tz: self.clone(),
+            is_dst,
+        }
+    }
+}
+
+impl Rule {
+    /// 规则在`year`年对应的当地时间
+    fn local(&self, year: i32) -> Option<NaiveDateTime> {
+        let date = match self.date {
+            RuleDate::Julian1(n) => {
+                let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                let ordinal = if leap && n >= 60 { n + 1 } else { n };
+                NaiveDate::from_yo_opt(year, ordinal as u32)?
+            }
+            RuleDate::Julian0(n) => {
+                NaiveDate::from_yo_opt(year, 1)?.checked_add_days(Days::new(n as u64))?
+            }
+            RuleDate::MonthWeekDay(month, n, weekday) => {
+                NaiveDate::nth_weekday_of_month(year, month, weekday, n)?
+            }
+        };
+        date.and_hms_opt(0, 0, 0)?
+            .checked_add_signed(Duration::seconds(self.time as i64))
+    }
+}
+
+impl TimeZone for PosixTz {
+    type Offset = PosixOffset;
+
+    fn from_offset(offset: &Self::Offset) -> Self {
+        offset.tz.clone()
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> MappedLocalTime<Self::Offset> {
+        self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> MappedLocalTime<Self::Offset> {
+        let Some(dst) = &self.dst else {
+            return MappedLocalTime::Single(self.offset(false));
+        };
+        // 先尝试夏令时，夏令时的偏移量更大，对应的UTC时间更早
+        let mut candidates = [(true, dst.offset), (false, self.std_offset)]
+            .into_iter()
+            .filter(|(is_dst, offset)| {
+                let utc = *local - Duration::seconds(*offset as i64);
+                self.is_dst_at(&utc) == *is_dst
+            })
+            .map(|(is_dst, _)| self.offset(is_dst));
+        match (candidates.next(), candidates.next()) {
+            (Some(first), Some(second)) => {
+                if first.fix().local_minus_utc() >= second.fix().local_minus_utc() {
+                    MappedLocalTime::Ambiguous(first, second)
+                } else {
+                    MappedLocalTime::Ambiguous(second, first)
+                }
+            }
+            (Some(offset), None) => MappedLocalTime::Single(offset),
+            _ => MappedLocalTime::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> Self::Offset {
+        self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+        self.offset(self.is_dst_at(utc))
+    }
+}
+
+impl LocalZone for PosixTz {
+    fn offset_at(&self, utc: &NaiveDateTime) -> Result<FixedOffset, DateUtilsError> {
+        Ok(self.offset_from_utc_datetime(utc).fix())
+    }
+}
+
+/// 省略夏令时规则时使用的美国规则，即`M3.2.0,M11.1.0`
+const DEFAULT_START: Rule = Rule {
+    date: RuleDate::MonthWeekDay(3, 2, Weekday::Sun),
+    time: 2 * 3600,
+};
+const DEFAULT_END: Rule = Rule {
+    date: RuleDate::MonthWeekDay(11, 1, Weekday::Sun),
+    time: 2 * 3600,
+};
+
+/// POSIX `TZ`字符串的解析器
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn eat(&mut self, c: char) -> bool {
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let end = self.rest.find(|c| !f(c)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    fn number(&mut self) -> Option<i32> {
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() || digits.len() > 3 {
+            return None;
+        }
+        digits.parse().ok()
+    }
+
+    /// 时区缩写，至少3个字母，或者用`<>`括起来的任意字母、数字和正负号
+    fn name(&mut self) -> Option<String> {
+        let name = if self.eat('<') {
+            let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-');
+            if !self.eat('>') {
+                return None;
+            }
+            name
+        } else {
+            self.take_while(|c| c.is_ascii_alphabetic())
+        };
+        (name.len() >= 3).then(|| name.to_string())
+    }
+
+    /// `[+-]hh[:mm[:ss]]`形式的秒数，小时最大为`max_hours`
+    fn time(&mut self, max_hours: i32) -> Option<i32> {
+        let sign = if self.eat('-') {
+            -1
+        } else {
+            self.eat('+');
+            1
+        };
+        let hours = self.number()?;
+        let mut seconds = hours * 3600;
+        for unit in [60, 1] {
+            if !self.eat(':') {
+                break;
+            }
+            let value = self.number().filter(|value| *value < 60)?;
+            seconds += value * unit;
+        }
+        (hours <= max_hours).then_some(sign * seconds)
+    }
+
+    /// UTC偏移量的秒数，以UTC以东为正，超出`FixedOffset`的范围时返回`None`
+    fn offset(&mut self) -> Option<i32> {
+        // POSIX的偏移量以UTC以西为正
+        self.time(24)
+            .map(|seconds| -seconds)
+            .filter(is_valid_offset)
+    }
+
+    fn rule(&mut self) -> Option<Rule> {
+        let date = if self.eat('J') {
+            RuleDate::Julian1(self.number().filter(|n| (1..=365).contains(n))? as u16)
+        } else if self.eat('M') {
+            let month = self.number().filter(|n| (1..=12).contains(n))?;
+            let week = self.eat('.').then(|| self.number())??;
+            let day = self.eat('.').then(|| self.number())??;
+            let weekday = match day {
+                0 => Weekday::Sun,
+                1..=6 => Weekday::try_from(day as u8 - 1).ok()?,
+                _ => return None,
+            };
+            let n = match week {
+                1..=4 => week as i8,
+                5 => -1,
+                _ => return None,
+            };
+            RuleDate::MonthWeekDay(month as u32, n, weekday)
+        } else {
+            RuleDate::Julian0(self.number().filter(|n| (0..=365).contains(n))? as u16)
+        };
+        // RFC 8536扩展了时间的范围，允许为负数和最大167小时
+        let time = if self.eat('/') {
+            self.time(167)?
+        } else {
+            2 * 3600
+        };
+        Some(Rule { date, time })
+    }
+
+    fn parse(&mut self) -> Option<PosixTz> {
+        let std_name = self.name()?;
+        let std_offset = self.offset()?;
+        if self.rest.is_empty() {
+            return Some(PosixTz {
+                std_name,
+                std_offset,
+                dst: None,
+            });
+        }
+        let name = self.name()?;
+        let offset = if self.rest.is_empty() || self.rest.starts_with(',') {
+            Some(std_offset + 3600).filter(is_valid_offset)?
+        } else {
+            self.offset()?
+        };
+        let (start, end) = if self.rest.is_empty() {
+            (DEFAULT_START, DEFAULT_END)
+        } else {
+            let start = self.eat(',').then(|| self.rule())??;
+            let end = self.eat(',').then(|| self.rule())??;
+            (start, end)
+        };
+        if !self.rest.is_empty() {
+            return None;
+        }
+        Some(PosixTz {
+            std_name,
+            std_offset,
+            dst: Some(Dst {
+                name,
+                offset,
+                start,
+                end,
+            }),
+        })
+    }
+}
+
+/// 偏移量能否转换为`FixedOffset`，即绝对值小于24小时
+fn is_valid_offset(seconds: &i32) -> bool {
+    FixedOffset::east_opt(*seconds).is_some()
+}
+
+impl FromStr for PosixTz {
+    type Err = DateUtilsError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // 环境变量`TZ`中的规则可以以`:`开头
+        let text = input.trim();
+        let text = text.strip_prefix(':').unwrap_or(text);
+        Parser { rest: text }
+            .parse()
+            .ok_or_else(|| DateUtilsError::Parse(input.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let tz: PosixTz = "CST6CDT,M3.2.0,M11.1.0".parse().unwrap();
+        assert_eq!(tz.std_name(), "CST");
+        assert_eq!(tz.std_offset(), FixedOffset::west_opt(6 * 3600).unwrap());
+        assert_eq!(tz.dst_name(), Some("CDT"));
+        assert_eq!(tz.dst_offset(), FixedOffset::west_opt(5 * 3600));
+
+        let tz: PosixTz = "<+0530>-5:30".parse().unwrap();
+        assert_eq!(tz.std_name(), "+0530");
+        assert_eq!(tz.std_offset(), FixedOffset::east_opt(19800).unwrap());
+        assert_eq!(tz.dst_name(), None);
+
+        let tz: PosixTz = "EST5EDT".parse().unwrap();
+        assert_eq!(tz, "EST5EDT,M3.2.0/2,M11.1.0/2".parse().unwrap());
+
+        assert!("".parse::<PosixTz>().is_err());
+        assert!("CS6".parse::<PosixTz>().is_err());
+        assert!("CST6CDT,M13.1.0,M11.1.0".parse::<PosixTz>().is_err());
+        assert!("CST6CDT,M3.2.0".parse::<PosixTz>().is_err());
+        assert!("CST6CDT,M3.2.0,M11.1.0x".parse::<PosixTz>().is_err());
+    }
+
+    #[test]
+    fn test_offset_from_utc() {
+        let tz: PosixTz = "CST6CDT,M3.2.0,M11.1.0".parse().unwrap();
+        let offset = tz.offset_from_utc_datetime(&datetime(2024, 1, 15, 12, 0));
+        assert!(!offset.is_dst());
+        assert_eq!(offset.to_string(), "CST");
+        // 2024年3月10日2点（CST）即8点（UTC）开始夏令时
+        let offset = tz.offset_from_utc_datetime(&datetime(2024, 3, 10, 7, 59));
+        assert!(!offset.is_dst());
+        let offset = tz.offset_from_utc_datetime(&datetime(2024, 3, 10, 8, 0));
+        assert!(offset.is_dst());
+        // 2024年11月3日2点（CDT）即7点（UTC）结束夏令时
+        let offset = tz.offset_from_utc_datetime(&datetime(2024, 11, 3, 6, 59));
+        assert!(offset.is_dst());
+        let offset = tz.offset_from_utc_datetime(&datetime(2024, 11, 3, 7, 0));
+        assert!(!offset.is_dst());
+    }
+
+    #[test]
+    fn test_southern_hemisphere() {
+        let tz: PosixTz = "AEST-10AEDT,M10.1.0,M4.1.0/3".parse().unwrap();
+        let offset = tz.offset_from_utc_datetime(&datetime(2024, 1, 15, 0, 0));
+        assert!(offset.is_dst());
+        assert_eq!(offset.fix(), FixedOffset::east_opt(11 * 3600).unwrap());
+        let offset = tz.offset_from_utc_datetime(&datetime(2024, 6, 15, 0, 0));
+        assert!(!offset.is_dst());
+    }
+
+    #[test]
+    fn test_local_time() {
+        let tz: PosixTz = "CST6CDT,M3.2.0,M11.1.0".parse().unwrap();
+        let local = tz
+            .from_local_datetime(&datetime(2024, 7, 1, 12, 0))
+            .unwrap();
+        assert_eq!(local.naive_utc(), datetime(2024, 7, 1, 17, 0));
+        // 夏令时开始时，2点到3点之间的本地时间不存在
+        let local = tz.from_local_datetime(&datetime(2024, 3, 10, 2, 30));
+        assert_eq!(local, MappedLocalTime::None);
+        // 夏令时结束时，1点到2点之间的本地时间出现两次
+        match tz.from_local_datetime(&datetime(2024, 11, 3, 1, 30)) {
+            MappedLocalTime::Ambiguous(earlier, later) => {
+                assert_eq!(earlier.naive_utc(), datetime(2024, 11, 3, 6, 30));
+                assert_eq!(later.naive_utc(), datetime(2024, 11, 3, 7, 30));
+                assert_eq!(earlier.offset().abbreviation(), "CDT");
+            }
+            result => panic!("unexpected {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_transitions() {
+        let tz: PosixTz = "CST6CDT,M3.2.0,M11.1.0".parse().unwrap();
+        let transitions = tz.transitions(2024);
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].utc, datetime(2024, 3, 10, 8, 0));
+        assert_eq!(transitions[0].local_before(), datetime(2024, 3, 10, 2, 0));
+        assert_eq!(transitions[0].local_after(), datetime(2024, 3, 10, 3, 0));
+        assert!(transitions[0].is_gap());
+        assert_eq!(transitions[1].utc, datetime(2024, 11, 3, 7, 0));
+        assert!(transitions[1].is_overlap());
+
+        let tz: PosixTz = "AEST-10AEDT,M10.1.0,M4.1.0/3".parse().unwrap();
+        let transitions = tz.transitions(2024);
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].local_before(), datetime(2024, 4, 7, 3, 0));
+        assert_eq!(transitions[1].local_before(), datetime(2024, 10, 6, 2, 0));
+
+        let tz: PosixTz = "JST-9".parse().unwrap();
+        assert!(tz.transitions(2024).is_empty());
+    }
+
+    #[test]
+    fn test_julian_rules() {
+        // 3月1日（第60天，不计2月29日）到11月1日（第305天，从0开始计算2月29日）
+        let tz: PosixTz = "XST3XDT,J60/0,305/0".parse().unwrap();
+        let transitions = tz.transitions(2024);
+        assert_eq!(transitions[0].local_before(), datetime(2024, 3, 1, 0, 0));
+        assert_eq!(transitions[1].local_before(), datetime(2024, 11, 1, 0, 0));
+    }
+
+    #[test]
+    fn test_out_of_range_offset() {
+        for input in [
+            "AAA24",
+            "AAA-24",
+            "AAA24:59:59",
+            "AAA5BBB24",
+            "AAA-23:30BBB",
+        ] {
+            assert_eq!(
+                input.parse::<PosixTz>(),
+                Err(DateUtilsError::Parse(input.to_string()))
+            );
+        }
+        let tz: PosixTz = "AAA-23:59:59".parse().unwrap();
+        assert_eq!(tz.std_offset(), FixedOffset::east_opt(86399).unwrap());
+    }
+}
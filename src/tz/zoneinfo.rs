@@ -0,0 +1,511 @@
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, MappedLocalTime, NaiveDate, NaiveDateTime, Offset, TimeZone,
+};
+
+use crate::error::DateUtilsError;
+use crate::now::LocalZone;
+use crate::tz::{PosixTz, Transition};
+
+/// 没有设置环境变量`TZDIR`时使用的时区数据目录
+const DEFAULT_ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+/// English: A named time zone read from a binary TZif (version 1 to 4) file, such as the files in
+/// `/usr/share/zoneinfo`. Times after the last transition in the file follow the POSIX rule in its footer.
+///
+/// 中文: 从二进制TZif（版本1到4）文件读取的具名时区，如`/usr/share/zoneinfo`中的文件。
+/// 文件中最后一次变化之后的时间按照文件末尾的POSIX规则计算
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneInfo {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Inner {
+    name: Option<String>,
+    /// 变化发生的UTC时间戳（秒）
+    transitions: Vec<i64>,
+    /// 每次变化之后的本地时间类型的序号
+    transition_types: Vec<usize>,
+    types: Vec<LocalType>,
+    footer: Option<PosixTz>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LocalType {
+    offset: i32,
+    is_dst: bool,
+    abbreviation: String,
+}
+
+/// English: The offset of a [`ZoneInfo`] at some time, together with its abbreviation
+///
+/// 中文: [`ZoneInfo`]在某个时间的偏移量，以及对应的时区缩写
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneInfoOffset {
+    zone: ZoneInfo,
+    offset: FixedOffset,
+    is_dst: bool,
+    abbreviation: String,
+}
+
+impl ZoneInfoOffset {
+    /// English: Whether daylight saving time is in effect
+    ///
+    /// 中文: 是否处于夏令时
+    pub fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// English: The abbreviation of the offset, such as `CST` or `CDT`
+    ///
+    /// 中文: 偏移量的缩写，如`CST`或`CDT`
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+}
+
+impl Offset for ZoneInfoOffset {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl Display for ZoneInfoOffset {
+    /// 输出时区缩写
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.abbreviation)
+    }
+}
+
+impl ZoneInfo {
+    /// English: Read the time zone by its IANA name, such as `Asia/Shanghai`, from the directory in
+    /// the `TZDIR` environment variable, or from `/usr/share/zoneinfo` when it is not set.
+    ///
+    /// 中文: 根据IANA名称读取时区，如`Asia/Shanghai`。从环境变量`TZDIR`指定的目录读取，没有设置时从`/usr/share/zoneinfo`读取
+    pub fn from_name(name: &str) -> Result<Self, DateUtilsError> {
+        let dir = env::var_os("TZDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_ZONEINFO_DIR));
+        Self::from_name_in(dir, name)
+    }
+
+    /// English: Read the time zone by its IANA name from the given zoneinfo directory
+    ///
+    /// 中文: 从指定的时区数据目录中根据IANA名称读取时区
+    pub fn from_name_in(dir: impl AsRef<Path>, name: &str) -> Result<Self, DateUtilsError> {
+        let unknown = || DateUtilsError::UnknownZone(name.to_string());
+        // 只允许目录中的相对路径，避免读取目录之外的文件
+        let relative = Path::new(name);
+        if name.is_empty()
+            || !relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(unknown());
+        }
+        let bytes = fs::read(dir.as_ref().join(relative)).map_err(|_| unknown())?;
+        let mut zone = parse(&bytes).ok_or_else(unknown)?;
+        Arc::get_mut(&mut zone.inner).unwrap().name = Some(name.to_string());
+        Ok(zone)
+    }
+
+    /// English: Read the time zone from a TZif file
+    ///
+    /// 中文: 从TZif文件读取时区
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, DateUtilsError> {
+        let path = path.as_ref();
+        let unknown = || DateUtilsError::UnknownZone(path.display().to_string());
+        let bytes = fs::read(path).map_err(|_| unknown())?;
+        parse(&bytes).ok_or_else(unknown)
+    }
+
+    /// English: Read the time zone from the content of a TZif file
+    ///
+    /// 中文: 从TZif文件的内容读取时区
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DateUtilsError> {
+        parse(bytes).ok_or_else(|| DateUtilsError::Parse("TZif data".to_string()))
+    }
+
+    /// English: The IANA name of the time zone, `None` when it is not read by name
+    ///
+    /// 中文: 时区的IANA名称，不是根据名称读取时为`None`
+    pub fn name(&self) -> Option<&str> {
+        self.inner.name.as_deref()
+    }
+
+    /// English: Get the transitions which take place in the year, in UTC order
+    ///
+    /// 中文: 获取某一年中发生的偏移量变化，按照UTC时间排序
+    pub fn transitions(&self, year: i32) -> Vec<Transition> {
+        let inner = &self.inner;
+        let mut transitions: Vec<Transition> = (0..inner.transitions.len())
+            .filter_map(|index| {
+                let before = match index {
+                    0 => &inner.types[0],
+                    _ => &inner.types[inner.transition_types[index - 1]],
+                };
+                let after = &inner.types[inner.transition_types[index]];
+                Some(Transition {
+                    utc: DateTime::from_timestamp(inner.transitions[index], 0)?.naive_utc(),
+                    before: FixedOffset::east_opt(before.offset)?,
+                    after: FixedOffset::east_opt(after.offset)?,
+                })
+            })
+            .filter(|transition| transition.before != transition.after)
+            .filter(|transition| transition.local_before().year() == year)
+            .collect();
+        if let Some(footer) = &inner.footer {
+            let last = inner.transitions.last().copied().unwrap_or(i64::MIN);
+            transitions.extend(
+                footer
+                    .transitions(year)
+                    .into_iter()
+                    .filter(|transition| transition.utc.and_utc().timestamp() > last),
+            );
+        }
+        transitions
+    }
+
+    fn offset_at_timestamp(&self, timestamp: i64) -> ZoneInfoOffset {
+        let inner = &self.inner;
+        // 最后一次变化之后按照页脚的规则计算
+        let footer_offset = match (inner.transitions.last(), &inner.footer) {
+            (Some(last), Some(footer)) if timestamp >= *last => {
+                DateTime::from_timestamp(timestamp, 0)
+                    .map(|utc| footer.offset_from_utc_datetime(&utc.naive_utc()))
+            }
+            _ => None,
+        };
+        if let Some(offset) = footer_offset {
+            return ZoneInfoOffset {
+                zone: self.clone(),
+                offset: offset.fix(),
+                is_dst: offset.is_dst(),
+                abbreviation: offset.abbreviation().to_string(),
+            };
+        }
+        let local_type = match inner.transitions.partition_point(|time| *time <= timestamp) {
+            0 => &inner.types[0],
+            index => &inner.types[inner.transition_types[index - 1]],
+        };
+        ZoneInfoOffset {
+            zone: self.clone(),
+            offset: FixedOffset::east_opt(local_type.offset).unwrap(),
+            is_dst: local_type.is_dst,
+            abbreviation: local_type.abbreviation.clone(),
+        }
+    }
+}
+
+impl TimeZone for ZoneInfo {
+    type Offset = ZoneInfoOffset;
+
+    fn from_offset(offset: &Self::Offset) -> Self {
+        offset.zone.clone()
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> MappedLocalTime<Self::Offset> {
+        self.offset_from_local_datetime(&local.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> MappedLocalTime<Self::Offset> {
+        // 本地时间附近可能的偏移量，假设两次变化的间隔超过一天
+        let timestamp = local.and_utc().timestamp();
+        let mut candidates: Vec<ZoneInfoOffset> = [-86400, 0, 86400]
+            .into_iter()
+            .map(|delta| self.offset_at_timestamp(timestamp + delta))
+            .filter(|offset| {
+                let utc = timestamp - offset.offset.local_minus_utc() as i64;
+                self.offset_at_timestamp(utc).offset == offset.offset
+            })
+            .collect();
+        // 偏移量越大，对应的UTC时间越早
+        candidates.sort_by_key(|offset| -offset.offset.local_minus_utc());
+        candidates.dedup_by_key(|offset| offset.offset);
+        let mut candidates = candidates.into_iter();
+        match (candidates.next(), candidates.next()) {
+            (Some(earlier), Some(later)) => MappedLocalTime::Ambiguous(earlier, later),
+            (Some(offset), None) => MappedLocalTime::Single(offset),
+            _ => MappedLocalTime::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> Self::Offset {
+        self.offset_from_utc_datetime(&utc.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> Self::Offset {
+        self.offset_at_timestamp(utc.and_utc().timestamp())
+    }
+}
+
+impl LocalZone for ZoneInfo {
+    fn offset_at(&self, utc: &NaiveDateTime) -> Result<FixedOffset, DateUtilsError> {
+        Ok(self.offset_from_utc_datetime(utc).fix())
+    }
+}
+
+/// TZif文件的读取器，所有整数均为大端序
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.bytes.len() < n {
+            return None;
+        }
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Some(taken)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        Some(i32::from_be_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        Some(i64::from_be_bytes(self.take(8)?.try_into().ok()?))
+    }
+}
+
+/// TZif文件头中的各项数量
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+fn header(reader: &mut Reader) -> Option<Header> {
+    if reader.take(4)? != b"TZif" {
+        return None;
+    }
+    let version = reader.u8()?;
+    reader.take(15)?;
+    let mut count = || reader.i32().and_then(|n| usize::try_from(n).ok());
+    Some(Header {
+        version,
+        isutcnt: count()?,
+        isstdcnt: count()?,
+        leapcnt: count()?,
+        timecnt: count()?,
+        typecnt: count()?,
+        charcnt: count()?,
+    })
+}
+
+/// 读取数据块，`wide`为真时时间为8字节（版本2及以上的第二个数据块）
+fn data(reader: &mut Reader, header: &Header, wide: bool) -> Option<Inner> {
+    let transitions = (0..header.timecnt)
+        .map(|_| {
+            if wide {
+                reader.i64()
+            } else {
+                reader.i32().map(i64::from)
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let transition_types = reader
+        .take(header.timecnt)?
+        .iter()
+        .map(|index| *index as usize)
+        .collect::<Vec<_>>();
+    let raw_types = (0..header.typecnt)
+        .map(|_| Some((reader.i32()?, reader.u8()? != 0, reader.u8()? as usize)))
+        .collect::<Option<Vec<_>>>()?;
+    let chars = reader.take(header.charcnt)?;
+    let leap_size = if wide { 12 } else { 8 };
+    reader.take(header.leapcnt * leap_size + header.isstdcnt + header.isutcnt)?;
+
+    let types = raw_types
+        .into_iter()
+        .map(|(offset, is_dst, index)| {
+            let rest = chars.get(index..)?;
+            let end = rest.iter().position(|c| *c == 0)?;
+            FixedOffset::east_opt(offset)?;
+            Some(LocalType {
+                offset,
+                is_dst,
+                abbreviation: String::from_utf8(rest[..end].to_vec()).ok()?,
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if types.is_empty()
+        || transition_types.iter().any(|index| *index >= types.len())
+        || transitions.windows(2).any(|pair| pair[0] >= pair[1])
+    {
+        return None;
+    }
+    Some(Inner {
+        name: None,
+        transitions,
+        transition_types,
+        types,
+        footer: None,
+    })
+}
+
+fn parse(bytes: &[u8]) -> Option<ZoneInfo> {
+    let mut reader = Reader { bytes };
+    let first = header(&mut reader)?;
+    let v1 = data(&mut reader, &first, false)?;
+    if first.version == 0 {
+        return Some(ZoneInfo {
+            inner: Arc::new(v1),
+        });
+    }
+    // 版本2及以上使用第二个数据块和文件末尾的POSIX规则
+    let second = header(&mut reader)?;
+    let mut inner = data(&mut reader, &second, true)?;
+    let footer = std::str::from_utf8(reader.bytes).ok()?;
+    let footer = footer.strip_prefix('\n')?.trim_end_matches('\n');
+    if !footer.is_empty() {
+        inner.footer = Some(footer.parse().ok()?);
+    }
+    Some(ZoneInfo {
+        inner: Arc::new(inner),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    /// 构造一个版本2文件：1970年1月1日UTC起从+01:00（AAA）变为+02:00（BBB，夏令时），
+    /// 1971年1月1日UTC起变回+01:00，之后按照页脚的欧盟夏令时规则
+    fn sample() -> Vec<u8> {
+        fn block(bytes: &mut Vec<u8>, wide: bool) {
+            bytes.extend_from_slice(b"TZif2");
+            bytes.extend_from_slice(&[0; 15]);
+            for count in [0i32, 0, 0, 2, 2, 8] {
+                bytes.extend_from_slice(&count.to_be_bytes());
+            }
+            for time in [0i64, 86400 * 365] {
+                if wide {
+                    bytes.extend_from_slice(&time.to_be_bytes());
+                } else {
+                    bytes.extend_from_slice(&(time as i32).to_be_bytes());
+                }
+            }
+            bytes.extend_from_slice(&[1, 0]);
+            bytes.extend_from_slice(&3600i32.to_be_bytes());
+            bytes.extend_from_slice(&[0, 0]);
+            bytes.extend_from_slice(&7200i32.to_be_bytes());
+            bytes.extend_from_slice(&[1, 4]);
+            bytes.extend_from_slice(b"AAA\0BBB\0");
+        }
+        let mut bytes = vec![];
+        block(&mut bytes, false);
+        block(&mut bytes, true);
+        bytes.extend_from_slice(b"\nAAA-1BBB,M3.5.0,M10.5.0/3\n");
+        bytes
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let zone = ZoneInfo::from_bytes(&sample()).unwrap();
+        assert_eq!(zone.name(), None);
+        let offset = zone.offset_from_utc_datetime(&datetime(1969, 6, 1, 0, 0));
+        assert_eq!(offset.to_string(), "AAA");
+        let offset = zone.offset_from_utc_datetime(&datetime(1970, 6, 1, 0, 0));
+        assert_eq!(offset.abbreviation(), "BBB");
+        assert!(offset.is_dst());
+        assert_eq!(offset.fix(), FixedOffset::east_opt(7200).unwrap());
+        // 最后一次变化之后使用页脚的规则
+        let offset = zone.offset_from_utc_datetime(&datetime(1990, 1, 1, 0, 0));
+        assert_eq!(offset.abbreviation(), "AAA");
+        let offset = zone.offset_from_utc_datetime(&datetime(1990, 6, 1, 0, 0));
+        assert_eq!(offset.abbreviation(), "BBB");
+        assert_eq!(offset.fix(), FixedOffset::east_opt(7200).unwrap());
+
+        assert!(ZoneInfo::from_bytes(b"TZif").is_err());
+        assert!(ZoneInfo::from_bytes(&sample()[..60]).is_err());
+    }
+
+    #[test]
+    fn test_local_time() {
+        let zone = ZoneInfo::from_bytes(&sample()).unwrap();
+        // 1970年1月1日01:00变为02:00，01:30不存在
+        let local = zone.from_local_datetime(&datetime(1970, 1, 1, 1, 30));
+        assert_eq!(local, MappedLocalTime::None);
+        let local = zone
+            .from_local_datetime(&datetime(1970, 1, 1, 0, 30))
+            .unwrap();
+        assert_eq!(local.naive_utc(), datetime(1969, 12, 31, 23, 30));
+        // 1971年1月1日02:00变回01:00，01:30出现两次
+        match zone.from_local_datetime(&datetime(1971, 1, 1, 1, 30)) {
+            MappedLocalTime::Ambiguous(earlier, later) => {
+                assert_eq!(earlier.naive_utc(), datetime(1970, 12, 31, 23, 30));
+                assert_eq!(later.naive_utc(), datetime(1971, 1, 1, 0, 30));
+            }
+            result => panic!("unexpected {:?}", result),
+        }
+        let local = zone
+            .from_local_datetime(&datetime(1971, 6, 1, 12, 0))
+            .unwrap();
+        assert_eq!(local.naive_utc(), datetime(1971, 6, 1, 10, 0));
+    }
+
+    #[test]
+    fn test_transitions() {
+        let zone = ZoneInfo::from_bytes(&sample()).unwrap();
+        let transitions = zone.transitions(1970);
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].utc, datetime(1970, 1, 1, 0, 0));
+        assert!(transitions[0].is_gap());
+        let transitions = zone.transitions(1990);
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].utc, datetime(1990, 3, 25, 1, 0));
+        assert!(zone.transitions(1960).is_empty());
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(
+            ZoneInfo::from_name_in("/usr/share/zoneinfo", "../etc/passwd"),
+            Err(DateUtilsError::UnknownZone("../etc/passwd".to_string()))
+        );
+        assert!(ZoneInfo::from_name_in("/usr/share/zoneinfo", "Mars/Olympus").is_err());
+        // 系统没有安装时区数据时跳过
+        let Ok(zone) = ZoneInfo::from_name_in("/usr/share/zoneinfo", "America/Chicago") else {
+            return;
+        };
+        assert_eq!(zone.name(), Some("America/Chicago"));
+        let local = zone.from_utc_datetime(&datetime(2024, 7, 1, 17, 0));
+        assert_eq!(local.naive_local(), datetime(2024, 7, 1, 12, 0));
+        assert_eq!(local.offset().abbreviation(), "CDT");
+        let local = zone.from_utc_datetime(&datetime(2040, 1, 1, 18, 0));
+        assert_eq!(local.naive_local(), datetime(2040, 1, 1, 12, 0));
+        match zone.from_local_datetime(&datetime(2024, 11, 3, 1, 30)) {
+            MappedLocalTime::Ambiguous(earlier, later) => {
+                assert_eq!(earlier.offset().abbreviation(), "CDT");
+                assert_eq!(later.offset().abbreviation(), "CST");
+            }
+            result => panic!("unexpected {:?}", result),
+        }
+        let transitions = zone.transitions(2024);
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].utc, datetime(2024, 3, 10, 8, 0));
+    }
+}
@@ -40,6 +40,15 @@ pub(crate) fn month_type(month: u32, year: i32) -> MonthType {
     }
 }
 
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month_type(month, year) {
+        MonthType::Day30 => 30,
+        MonthType::Day31 => 31,
+        MonthType::Other(false) => 28,
+        MonthType::Other(true) => 29,
+    }
+}
+
 pub fn second2minute(second: u32) -> u32 {
     second % 60
 }
@@ -76,6 +85,14 @@ mod tests {
         assert!(is_leap_year(2004));
     }
 
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 4), 30);
+        assert_eq!(days_in_month(2000, 12), 31);
+    }
+
     #[test]
     fn test_second2minute() {
         let second = 61;
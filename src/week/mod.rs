@@ -1,7 +1,15 @@
 use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, Weekday};
 use std::ops::{Add, Sub};
 use crate::day::DayHelper;
+use crate::error::DateUtilsError;
 use crate::month::MonthHelper;
+use crate::rounding::{NANOS_PER_WEEK, Rounding, fraction, split_duration};
+use crate::utils::days_in_month;
+use crate::week_convention::WeekConvention;
+
+mod year_week;
+
+pub use year_week::YearWeek;
 
 pub trait WeekHelper {
     /// English: is monday
@@ -60,6 +68,24 @@ pub trait WeekHelper {
     fn sub_weeks_opt(&self, week: u64) -> Option<Self>
     where
         Self: Sized;
+    /// English: Add the specified number of week to the given date, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 给指定的日期添加指定的周数，溢出时返回错误而不是panic
+    fn try_add_weeks(&self, week: u64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.add_weeks_opt(week).ok_or(DateUtilsError::OutOfRange)
+    }
+    /// English: Subtract the specified number of week to the given date, returning an error instead of panicking when overflowed.
+    ///
+    /// 中文: 给指定的日期减去指定的周数，溢出时返回错误而不是panic
+    fn try_sub_weeks(&self, week: u64) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized,
+    {
+        self.sub_weeks_opt(week).ok_or(DateUtilsError::OutOfRange)
+    }
     /// English: Return the end of a week for the given date. The result will be in the local timezone. The week starts on Monday.
     ///
     /// 中文: 返回指定日期所在周的结束日期，以周一为一个周的开始日期
@@ -120,6 +146,107 @@ pub trait WeekHelper {
     ///
     /// 中文: 获取指定日期所在月的周数,指定的星期开始
     fn weeks_of_month_with(&self, weekday: Weekday) -> u8;
+    /// English: Get the week of the year. The week starts on Monday and the first week has at least 4 days
+    /// in the year, so the first days of January may belong to the last week of the previous year.
+    ///
+    /// 中文: 获取指定日期是一年中的第几周,周一为一周的第一天,第一周至少包含该年的4天,所以一月初的几天可能属于上一年的最后一周
+    fn week_of_year(&self) -> u8;
+    /// English: Get the week of the year. The week starts on Sunday and the week containing January 1st is the first week.
+    ///
+    /// 中文: 获取指定日期是一年中的第几周,周日为一周的第一天,包含1月1日的那一周为第一周
+    fn week_of_year0(&self) -> u8;
+    /// English: Get the week of the year. The week starts on the specified weekday and the first week has
+    /// at least `min_days_in_first_week` (1 to 7) days in the year.
+    ///
+    /// 中文: 获取指定日期是一年中的第几周,指定的星期开始,第一周至少包含该年的`min_days_in_first_week`(1到7)天
+    fn week_of_year_with(&self, weekday: Weekday, min_days_in_first_week: u8) -> u8;
+    /// English: Get the number of weeks in the week-numbering year which has the same number as the year of the given date.
+    ///
+    /// 中文: 获取与指定日期年份相同的周年中的周数
+    fn weeks_in_year_with(&self, weekday: Weekday, min_days_in_first_week: u8) -> u8;
+    /// English: Get the first day of the n-th week (starting from 1) of the week-numbering year of the date, using the rule of `week_of_year`.
+    /// Panic when n is 0 or greater than the number of weeks in the week-numbering year.
+    ///
+    /// 中文: 获取所在周年第n周(从1开始)的第一天,使用`week_of_year`的规则,n为0或超出该周年的周数时panic
+    fn begin_of_week_of_year(&self, n: u8) -> Self;
+    /// English: Get the first day of the n-th week (starting from 1) of the week-numbering year of the date, using the rule of `week_of_year`.
+    /// Return None when n is 0 or greater than the number of weeks in the week-numbering year.
+    ///
+    /// 中文: 获取所在周年第n周(从1开始)的第一天,使用`week_of_year`的规则,n为0或超出该周年的周数时返回None
+    fn begin_of_week_of_year_opt(&self, n: u8) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Get the first day of the n-th week (starting from 1) of the week-numbering year of the date, using the given rule.
+    /// Panic when n is 0 or greater than the number of weeks in the week-numbering year.
+    ///
+    /// 中文: 获取所在周年第n周(从1开始)的第一天,使用指定的规则,n为0或超出该周年的周数时panic
+    fn begin_of_week_of_year_with(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Self;
+    /// English: Get the first day of the n-th week (starting from 1) of the week-numbering year of the date, using the given rule.
+    /// Return None when n is 0 or greater than the number of weeks in the week-numbering year.
+    ///
+    /// 中文: 获取所在周年第n周(从1开始)的第一天,使用指定的规则,n为0或超出该周年的周数时返回None
+    fn begin_of_week_of_year_with_opt(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Move the date to the n-th week of its week-numbering year and keep the weekday, using the rule of `week_of_year`.
+    /// Panic when n is 0 or greater than the number of weeks in the week-numbering year.
+    ///
+    /// 中文: 将日期移动到所在周年的第n周,并保持星期几不变,使用`week_of_year`的规则,n为0或超出该周年的周数时panic
+    fn set_week_of_year(&self, n: u8) -> Self;
+    /// English: Move the date to the n-th week of its week-numbering year and keep the weekday, using the rule of `week_of_year`.
+    /// Return None when n is 0 or greater than the number of weeks in the week-numbering year.
+    ///
+    /// 中文: 将日期移动到所在周年的第n周,并保持星期几不变,使用`week_of_year`的规则,n为0或超出该周年的周数时返回None
+    fn set_week_of_year_opt(&self, n: u8) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Move the date to the n-th week of its week-numbering year and keep the weekday, using the given rule.
+    /// Panic when n is 0 or greater than the number of weeks in the week-numbering year.
+    ///
+    /// 中文: 将日期移动到所在周年的第n周,并保持星期几不变,使用指定的规则,n为0或超出该周年的周数时panic
+    fn set_week_of_year_with(&self, n: u8, weekday: Weekday, min_days_in_first_week: u8) -> Self;
+    /// English: Move the date to the n-th week of its week-numbering year and keep the weekday, using the given rule.
+    /// Return None when n is 0 or greater than the number of weeks in the week-numbering year.
+    ///
+    /// 中文: 将日期移动到所在周年的第n周,并保持星期几不变,使用指定的规则,n为0或超出该周年的周数时返回None
+    fn set_week_of_year_with_opt(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Get the first day of the week, using the first day of the week of the given locale, such as `en-US`.
+    ///
+    /// 中文: 获取一周的第一天,使用指定语言环境(如`en-US`)的一周的第一天
+    fn begin_of_week_in(&self, locale: &str) -> Self;
+    /// English: Is the date a weekend day in the given locale, such as `ar-EG`.
+    ///
+    /// 中文: 在指定语言环境(如`ar-EG`)中是否是周末
+    fn is_weekend_in(&self, locale: &str) -> bool;
+    /// English: Is the date a workday in the given locale, such as `ar-EG`.
+    ///
+    /// 中文: 在指定语言环境(如`ar-EG`)中是否是工作日
+    fn is_workday_in(&self, locale: &str) -> bool;
+    /// English: Get the week of the month, using the first day of the week of the given locale.
+    ///
+    /// 中文: 获取指定日期是当月的第几周,使用指定语言环境的一周的第一天
+    fn week_of_month_in(&self, locale: &str) -> u8;
+    /// English: Get the week of the year, using the first day of the week and the first-week rule of the given locale.
+    ///
+    /// 中文: 获取指定日期是一年中的第几周,使用指定语言环境的一周的第一天和第一周的规则
+    fn week_of_year_in(&self, locale: &str) -> u8;
     /// English:Are the given dates in the same week. The week starts on Monday.
     ///
     /// 中文: 两个日期是否在同一周,周一为一周的第一天
@@ -148,6 +275,14 @@ pub trait WeekHelper {
     ///
     /// 中文: 获取两个日期之间的完整周数,默认会截断小数部分
     fn diff_weeks(&self, other: &Self) -> i64;
+    /// English: Get the number of weeks between the given dates with the given rounding method.
+    ///
+    /// 中文: 按照指定的取整方式计算两个日期之间的周数
+    fn diff_weeks_with(&self, other: &Self, rounding: Rounding) -> i64;
+    /// English: Get the number of weeks between the given dates, including the fractional part.
+    ///
+    /// 中文: 计算两个日期之间的周数，包含小数部分
+    fn diff_weeks_f64(&self, other: &Self) -> f64;
     /// English: When is the next day of the week?
     ///
     /// 中文: 下一周的日期
@@ -212,6 +347,34 @@ pub trait WeekHelper {
     ///
     /// 中文: 上周日的日期
     fn previous_sunday(&self) -> Self;
+    /// English: Get the `n`th given weekday of the month, a negative `n` counts from the end of
+    /// the month, e.g. `-1` is the last one. Return `None` when there is no such day.
+    ///
+    /// 中文: 获取某月的第`n`个指定星期几，`n`为负数时从月末开始计算，如`-1`为最后一个。不存在时返回`None`
+    fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: i8) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Move to the `n`th given weekday of the same month, a negative `n` counts from the
+    /// end of the month. Return `None` when there is no such day.
+    ///
+    /// 中文: 设置为当月的第`n`个指定星期几，`n`为负数时从月末开始计算。不存在时返回`None`
+    fn set_nth_weekday_of_month(&self, weekday: Weekday, n: i8) -> Option<Self>
+    where
+        Self: Sized;
+    /// English: Get the ordinal of the weekday in the month, e.g. 2 for the 2nd Tuesday
+    ///
+    /// 中文: 获取日期是当月的第几个同一星期几，如第2个星期二为2
+    fn weekday_ordinal_in_month(&self) -> u8;
+    /// English: Whether the date is the last one of its weekday in the month
+    ///
+    /// 中文: 日期是否是当月最后一个同一星期几
+    fn is_last_weekday_of_month(&self) -> bool;
+    /// English: Count the given weekday from `start` to `end`, both inclusive
+    ///
+    /// 中文: 计算从`start`到`end`（包含两端）之间指定星期几的个数
+    fn count_weekday_between(start: &Self, end: &Self, weekday: Weekday) -> u64
+    where
+        Self: Sized;
 }
 impl WeekHelper for NaiveDate {
     fn is_monday(&self) -> bool {
@@ -345,6 +508,112 @@ impl WeekHelper for NaiveDate {
         last_day.week_of_month_with(weekday)
     }
 
+    fn week_of_year(&self) -> u8 {
+        self.week_of_year_with(Weekday::Mon, 4)
+    }
+
+    fn week_of_year0(&self) -> u8 {
+        self.week_of_year_with(Weekday::Sun, 1)
+    }
+
+    fn week_of_year_with(&self, weekday: Weekday, min_days_in_first_week: u8) -> u8 {
+        let first_day = begin_of_week_year(self, weekday, min_days_in_first_week);
+        (self.diff_days(&first_day) / 7) as u8 + 1
+    }
+
+    fn weeks_in_year_with(&self, weekday: Weekday, min_days_in_first_week: u8) -> u8 {
+        let first_day = first_day_of_week_year(self.year(), weekday, min_days_in_first_week);
+        let next_first_day =
+            first_day_of_week_year(self.year() + 1, weekday, min_days_in_first_week);
+        (next_first_day.diff_days(&first_day) / 7) as u8
+    }
+
+    fn begin_of_week_of_year(&self, n: u8) -> Self {
+        self.begin_of_week_of_year_opt(n).unwrap()
+    }
+
+    fn begin_of_week_of_year_opt(&self, n: u8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.begin_of_week_of_year_with_opt(n, Weekday::Mon, 4)
+    }
+
+    fn begin_of_week_of_year_with(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Self {
+        self.begin_of_week_of_year_with_opt(n, weekday, min_days_in_first_week)
+            .unwrap()
+    }
+
+    fn begin_of_week_of_year_with_opt(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let first_day = begin_of_week_year(self, weekday, min_days_in_first_week);
+        let date = first_day.checked_add_days(Days::new(7 * (n as u64).checked_sub(1)?))?;
+        // 第n周的第一天仍然属于同一个周年
+        (begin_of_week_year(&date, weekday, min_days_in_first_week) == first_day).then_some(date)
+    }
+
+    fn set_week_of_year(&self, n: u8) -> Self {
+        self.set_week_of_year_opt(n).unwrap()
+    }
+
+    fn set_week_of_year_opt(&self, n: u8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.set_week_of_year_with_opt(n, Weekday::Mon, 4)
+    }
+
+    fn set_week_of_year_with(&self, n: u8, weekday: Weekday, min_days_in_first_week: u8) -> Self {
+        self.set_week_of_year_with_opt(n, weekday, min_days_in_first_week)
+            .unwrap()
+    }
+
+    fn set_week_of_year_with_opt(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let begin = self.begin_of_week_of_year_with_opt(n, weekday, min_days_in_first_week)?;
+        begin.checked_add_days(Days::new(days_from(weekday, self.weekday()) as u64))
+    }
+
+    fn begin_of_week_in(&self, locale: &str) -> Self {
+        self.begin_of_week_with(WeekConvention::for_locale(locale).first_day())
+    }
+
+    fn is_weekend_in(&self, locale: &str) -> bool {
+        WeekConvention::for_locale(locale).is_weekend(self.weekday())
+    }
+
+    fn is_workday_in(&self, locale: &str) -> bool {
+        !self.is_weekend_in(locale)
+    }
+
+    fn week_of_month_in(&self, locale: &str) -> u8 {
+        self.week_of_month_with(WeekConvention::for_locale(locale).first_day())
+    }
+
+    fn week_of_year_in(&self, locale: &str) -> u8 {
+        let convention = WeekConvention::for_locale(locale);
+        self.week_of_year_with(convention.first_day(), convention.min_days_in_first_week())
+    }
+
     fn is_same_week(&self, other: &Self) -> bool {
         self.week(Weekday::Mon).first_day() == other.week(Weekday::Mon).first_day()
     }
@@ -374,6 +643,16 @@ impl WeekHelper for NaiveDate {
         diff / 7
     }
 
+    fn diff_weeks_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_WEEK);
+        rounding.apply(whole, remainder, NANOS_PER_WEEK)
+    }
+
+    fn diff_weeks_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_WEEK);
+        fraction(whole, remainder, NANOS_PER_WEEK)
+    }
+
     fn next_day(&self, weekday: Weekday) -> Self {
         let left_span = 7 - self.weekday().number_from_monday();
         let right_span = weekday.number_from_monday();
@@ -443,6 +722,58 @@ impl WeekHelper for NaiveDate {
     fn previous_sunday(&self) -> Self {
         self.previous_day(Weekday::Sun)
     }
+
+    fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: i8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let first_day = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let days = days_in_month(year, month) as i64;
+        let day = match n {
+            0 => return None,
+            1.. => {
+                let offset = days_from(first_day.weekday(), weekday);
+                1 + offset + 7 * (n as i64 - 1)
+            }
+            _ => {
+                let last_day = first_day.with_day(days as u32)?;
+                let offset = days_from(weekday, last_day.weekday());
+                days - offset - 7 * (-(n as i64) - 1)
+            }
+        };
+        if (1..=days).contains(&day) {
+            first_day.with_day(day as u32)
+        } else {
+            None
+        }
+    }
+
+    fn set_nth_weekday_of_month(&self, weekday: Weekday, n: i8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::nth_weekday_of_month(self.year(), self.month(), weekday, n)
+    }
+
+    fn weekday_ordinal_in_month(&self) -> u8 {
+        ((self.day() - 1) / 7 + 1) as u8
+    }
+
+    fn is_last_weekday_of_month(&self) -> bool {
+        self.day() + 7 > days_in_month(self.year(), self.month())
+    }
+
+    fn count_weekday_between(start: &Self, end: &Self, weekday: Weekday) -> u64
+    where
+        Self: Sized,
+    {
+        if end < start {
+            return 0;
+        }
+        let days = (*end - *start).num_days() as u64 + 1;
+        let extra = (days_from(start.weekday(), weekday) as u64) < days % 7;
+        days / 7 + extra as u64
+    }
 }
 
 impl WeekHelper for NaiveDateTime {
@@ -562,6 +893,112 @@ impl WeekHelper for NaiveDateTime {
         self.date().weeks_of_month_with(weekday)
     }
 
+    fn week_of_year(&self) -> u8 {
+        self.date().week_of_year()
+    }
+
+    fn week_of_year0(&self) -> u8 {
+        self.date().week_of_year0()
+    }
+
+    fn week_of_year_with(&self, weekday: Weekday, min_days_in_first_week: u8) -> u8 {
+        self.date()
+            .week_of_year_with(weekday, min_days_in_first_week)
+    }
+
+    fn weeks_in_year_with(&self, weekday: Weekday, min_days_in_first_week: u8) -> u8 {
+        self.date()
+            .weeks_in_year_with(weekday, min_days_in_first_week)
+    }
+
+    fn begin_of_week_of_year(&self, n: u8) -> Self {
+        self.begin_of_week_of_year_opt(n).unwrap()
+    }
+
+    fn begin_of_week_of_year_opt(&self, n: u8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.begin_of_week_of_year_with_opt(n, Weekday::Mon, 4)
+    }
+
+    fn begin_of_week_of_year_with(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Self {
+        self.begin_of_week_of_year_with_opt(n, weekday, min_days_in_first_week)
+            .unwrap()
+    }
+
+    fn begin_of_week_of_year_with_opt(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.date()
+            .begin_of_week_of_year_with_opt(n, weekday, min_days_in_first_week)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    }
+
+    fn set_week_of_year(&self, n: u8) -> Self {
+        self.set_week_of_year_opt(n).unwrap()
+    }
+
+    fn set_week_of_year_opt(&self, n: u8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.set_week_of_year_with_opt(n, Weekday::Mon, 4)
+    }
+
+    fn set_week_of_year_with(&self, n: u8, weekday: Weekday, min_days_in_first_week: u8) -> Self {
+        self.set_week_of_year_with_opt(n, weekday, min_days_in_first_week)
+            .unwrap()
+    }
+
+    fn set_week_of_year_with_opt(
+        &self,
+        n: u8,
+        weekday: Weekday,
+        min_days_in_first_week: u8,
+    ) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.date()
+            .set_week_of_year_with_opt(n, weekday, min_days_in_first_week)
+            .map(|date| date.and_time(self.time()))
+    }
+
+    fn begin_of_week_in(&self, locale: &str) -> Self {
+        self.date()
+            .begin_of_week_in(locale)
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn is_weekend_in(&self, locale: &str) -> bool {
+        self.date().is_weekend_in(locale)
+    }
+
+    fn is_workday_in(&self, locale: &str) -> bool {
+        self.date().is_workday_in(locale)
+    }
+
+    fn week_of_month_in(&self, locale: &str) -> u8 {
+        self.date().week_of_month_in(locale)
+    }
+
+    fn week_of_year_in(&self, locale: &str) -> u8 {
+        self.date().week_of_year_in(locale)
+    }
+
     fn is_same_week(&self, other: &Self) -> bool {
         self.date().is_same_week(&other.date())
     }
@@ -596,6 +1033,16 @@ impl WeekHelper for NaiveDateTime {
         self.diff_days(other) / 7
     }
 
+    fn diff_weeks_with(&self, other: &Self, rounding: Rounding) -> i64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_WEEK);
+        rounding.apply(whole, remainder, NANOS_PER_WEEK)
+    }
+
+    fn diff_weeks_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder) = split_duration(*self - *other, NANOS_PER_WEEK);
+        fraction(whole, remainder, NANOS_PER_WEEK)
+    }
+
     fn next_day(&self, weekday: Weekday) -> Self {
         self.date().next_day(weekday).and_hms_opt(0, 0, 0).unwrap()
     }
@@ -662,4 +1109,61 @@ impl WeekHelper for NaiveDateTime {
     fn previous_sunday(&self) -> Self {
         self.previous_day(Weekday::Sun)
     }
+
+    /// 时间为零点
+    fn nth_weekday_of_month(year: i32, month: u32, weekday: Weekday, n: i8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        NaiveDate::nth_weekday_of_month(year, month, weekday, n)
+            .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn set_nth_weekday_of_month(&self, weekday: Weekday, n: i8) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        self.date()
+            .set_nth_weekday_of_month(weekday, n)
+            .map(|date| date.and_time(self.time()))
+    }
+
+    fn weekday_ordinal_in_month(&self) -> u8 {
+        self.date().weekday_ordinal_in_month()
+    }
+
+    fn is_last_weekday_of_month(&self) -> bool {
+        self.date().is_last_weekday_of_month()
+    }
+
+    /// 只比较日期部分
+    fn count_weekday_between(start: &Self, end: &Self, weekday: Weekday) -> u64
+    where
+        Self: Sized,
+    {
+        NaiveDate::count_weekday_between(&start.date(), &end.date(), weekday)
+    }
+}
+
+/// 从星期`from`向后到星期`to`的天数，取值为0到6
+fn days_from(from: Weekday, to: Weekday) -> i64 {
+    (to.num_days_from_monday() as i64 - from.num_days_from_monday() as i64).rem_euclid(7)
+}
+
+/// 周年的第一周是包含该年第`min_days_in_first_week`天的那一周
+fn first_day_of_week_year(year: i32, weekday: Weekday, min_days_in_first_week: u8) -> NaiveDate {
+    let min_days = min_days_in_first_week.clamp(1, 7) as u32;
+    NaiveDate::from_ymd_opt(year, 1, min_days)
+        .unwrap()
+        .begin_of_week_with(weekday)
+}
+
+/// 获取日期所在周年的第一周的第一天
+fn begin_of_week_year(date: &NaiveDate, weekday: Weekday, min_days_in_first_week: u8) -> NaiveDate {
+    let year = date.year();
+    [year + 1, year, year - 1]
+        .into_iter()
+        .map(|year| first_day_of_week_year(year, weekday, min_days_in_first_week))
+        .find(|first_day| first_day <= date)
+        .unwrap()
 }
@@ -0,0 +1,175 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, Duration, NaiveDate, Weekday};
+
+use crate::error::DateUtilsError;
+use crate::week::WeekHelper;
+
+/// English: An ISO 8601 week of a specific week-year, such as `2024-W05`. Every week starts on Monday.
+///
+/// 中文: 某个ISO 8601周年中的某一周，如`2024-W05`。每周从星期一开始
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearWeek {
+    year: i32,
+    week: u32,
+}
+
+impl YearWeek {
+    /// English: Create a year week, panic when the week does not exist in the week-year
+    ///
+    /// 中文: 创建某周年的某一周，该周在周年中不存在时panic
+    pub fn new(year: i32, week: u32) -> Self {
+        Self::try_new(year, week).unwrap()
+    }
+
+    /// English: Create a year week, return an error when the week does not exist in the week-year
+    ///
+    /// 中文: 创建某周年的某一周，该周在周年中不存在时返回错误
+    pub fn try_new(year: i32, week: u32) -> Result<Self, DateUtilsError> {
+        NaiveDate::from_isoywd_opt(year, week, Weekday::Mon)
+            .map(YearWeek::from)
+            .ok_or(DateUtilsError::InvalidDate)
+    }
+
+    /// English: Get the ISO week-year
+    ///
+    /// 中文: 获取ISO周年
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// English: Get the ISO week number, from 1 to 53
+    ///
+    /// 中文: 获取ISO周数，取值为1到53
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// English: Get the first day (Monday) of the week
+    ///
+    /// 中文: 获取该周的第一天（星期一）
+    pub fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_isoywd_opt(self.year, self.week, Weekday::Mon).unwrap()
+    }
+
+    /// English: Get the last day (Sunday) of the week
+    ///
+    /// 中文: 获取该周的最后一天（星期日）
+    pub fn last_day(&self) -> NaiveDate {
+        self.first_day() + Days::new(6)
+    }
+
+    /// English: Whether the date is in the week
+    ///
+    /// 中文: 日期是否在该周中
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        YearWeek::from(*date) == *self
+    }
+
+    /// English: Iterate over the days of the week
+    ///
+    /// 中文: 遍历该周的每一天
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        self.first_day().iter_days().take(7)
+    }
+
+    /// English: Get the next week
+    ///
+    /// 中文: 获取下一周
+    pub fn succ(&self) -> YearWeek {
+        *self + 1
+    }
+
+    /// English: Get the previous week
+    ///
+    /// 中文: 获取上一周
+    pub fn pred(&self) -> YearWeek {
+        *self - 1
+    }
+
+    /// English: Add weeks, return `None` when the result is out of range
+    ///
+    /// 中文: 增加周数，结果超出范围时返回`None`
+    pub fn checked_add(&self, weeks: i32) -> Option<YearWeek> {
+        self.first_day()
+            .checked_add_signed(Duration::weeks(weeks as i64))
+            .map(YearWeek::from)
+    }
+
+    /// English: Subtract weeks, return `None` when the result is out of range
+    ///
+    /// 中文: 减少周数，结果超出范围时返回`None`
+    pub fn checked_sub(&self, weeks: i32) -> Option<YearWeek> {
+        self.first_day()
+            .checked_sub_signed(Duration::weeks(weeks as i64))
+            .map(YearWeek::from)
+    }
+}
+
+impl From<NaiveDate> for YearWeek {
+    fn from(date: NaiveDate) -> Self {
+        let week = date.begin_of_week().iso_week();
+        YearWeek {
+            year: week.year(),
+            week: week.week(),
+        }
+    }
+}
+
+impl Add<i32> for YearWeek {
+    type Output = YearWeek;
+
+    /// 结果超出范围时panic，使用`checked_add`可以避免panic
+    fn add(self, weeks: i32) -> Self::Output {
+        self.checked_add(weeks).unwrap()
+    }
+}
+
+impl Sub<i32> for YearWeek {
+    type Output = YearWeek;
+
+    /// 结果超出范围时panic，使用`checked_sub`可以避免panic
+    fn sub(self, weeks: i32) -> Self::Output {
+        self.checked_sub(weeks).unwrap()
+    }
+}
+
+impl Sub<YearWeek> for YearWeek {
+    type Output = i64;
+
+    /// 两周之间相差的周数
+    fn sub(self, other: YearWeek) -> Self::Output {
+        (self.first_day() - other.first_day()).num_weeks()
+    }
+}
+
+impl Display for YearWeek {
+    /// 默认输出`2024-W05`的形式，使用`{:#}`时输出`2024年第5周`的形式
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}年第{}周", self.year, self.week)
+        } else {
+            write!(f, "{}-W{:02}", self.year, self.week)
+        }
+    }
+}
+
+impl FromStr for YearWeek {
+    type Err = DateUtilsError;
+
+    /// 支持`2024-W05`和`2024年第5周`的形式
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || DateUtilsError::Parse(input.to_string());
+        let text = input.trim();
+        let (year, week) = match text.strip_suffix('周') {
+            Some(text) => text.split_once("年第"),
+            None => text.rsplit_once("-W").filter(|(_, week)| week.len() == 2),
+        }
+        .ok_or_else(error)?;
+        let year = year.parse::<i32>().map_err(|_| error())?;
+        let week = week.parse::<u32>().map_err(|_| error())?;
+        YearWeek::try_new(year, week).map_err(|_| error())
+    }
+}
@@ -0,0 +1,182 @@
+use chrono::Weekday;
+
+/// English: The week convention of a region: the first day of the week, the weekend days and the
+/// minimal number of days in the first week of the year. The data is derived from the CLDR week data.
+///
+/// 中文: 一个地区的周习惯：一周的第一天、周末以及一年中第一周最少包含的天数。数据来自CLDR的周数据
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekConvention {
+    first_day: Weekday,
+    weekend: &'static [Weekday],
+    min_days_in_first_week: u8,
+}
+
+/// 周日为一周第一天的地区
+const SUNDAY_FIRST: [&str; 56] = [
+    "AG", "AS", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO", "DM", "DO", "ET", "GT", "GU",
+    "HK", "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT", "MX",
+    "MZ", "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT", "TW",
+    "UM", "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+/// 周六为一周第一天的地区
+const SATURDAY_FIRST: [&str; 15] = [
+    "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY",
+];
+
+/// 周五为一周第一天的地区
+const FRIDAY_FIRST: [&str; 1] = ["MV"];
+
+/// 周末为周五和周六的地区
+const FRIDAY_SATURDAY_WEEKEND: [&str; 15] = [
+    "AE", "BH", "DZ", "EG", "IL", "IQ", "JO", "KW", "LY", "OM", "QA", "SA", "SD", "SY", "YE",
+];
+
+/// 周末为周四和周五的地区
+const THURSDAY_FRIDAY_WEEKEND: [&str; 1] = ["AF"];
+
+/// 周末只有周五的地区
+const FRIDAY_WEEKEND: [&str; 1] = ["IR"];
+
+/// 周末只有周日的地区
+const SUNDAY_WEEKEND: [&str; 2] = ["IN", "UG"];
+
+/// 第一周至少包含4天的地区
+const FOUR_DAYS_FIRST_WEEK: [&str; 42] = [
+    "AD", "AN", "AT", "AX", "BE", "BG", "CH", "CZ", "DE", "DK", "EE", "ES", "FI", "FJ", "FO", "FR",
+    "GB", "GF", "GG", "GI", "GP", "GR", "HU", "IE", "IM", "IS", "IT", "JE", "LI", "LT", "LU", "MC",
+    "MQ", "NL", "NO", "PL", "RE", "RU", "SE", "SJ", "SK", "SM",
+];
+
+impl WeekConvention {
+    /// English: The convention used when the region is unknown: the week starts on Monday and the
+    /// weekend is Saturday and Sunday.
+    ///
+    /// 中文: 地区未知时使用的习惯：一周从周一开始，周末为周六和周日
+    pub const WORLD: WeekConvention = WeekConvention {
+        first_day: Weekday::Mon,
+        weekend: &[Weekday::Sat, Weekday::Sun],
+        min_days_in_first_week: 1,
+    };
+
+    /// English: Get the convention of a region, such as `US` or `EG`. The region code is case-insensitive.
+    ///
+    /// 中文: 获取某个地区的习惯，如`US`或`EG`。地区代码不区分大小写
+    pub fn for_region(region: &str) -> WeekConvention {
+        let region = region.to_ascii_uppercase();
+        let region = region.as_str();
+        let first_day = if SUNDAY_FIRST.contains(&region) {
+            Weekday::Sun
+        } else if SATURDAY_FIRST.contains(&region) {
+            Weekday::Sat
+        } else if FRIDAY_FIRST.contains(&region) {
+            Weekday::Fri
+        } else {
+            Weekday::Mon
+        };
+        let weekend: &'static [Weekday] = if FRIDAY_SATURDAY_WEEKEND.contains(&region) {
+            &[Weekday::Fri, Weekday::Sat]
+        } else if THURSDAY_FRIDAY_WEEKEND.contains(&region) {
+            &[Weekday::Thu, Weekday::Fri]
+        } else if FRIDAY_WEEKEND.contains(&region) {
+            &[Weekday::Fri]
+        } else if SUNDAY_WEEKEND.contains(&region) {
+            &[Weekday::Sun]
+        } else {
+            &[Weekday::Sat, Weekday::Sun]
+        };
+        let min_days_in_first_week = if FOUR_DAYS_FIRST_WEEK.contains(&region) {
+            4
+        } else {
+            1
+        };
+        WeekConvention {
+            first_day,
+            weekend,
+            min_days_in_first_week,
+        }
+    }
+
+    /// English: Get the convention of a locale, such as `en-US`, `ar_EG` or `zh-Hans-CN`.
+    /// The region subtag is used, and [`WeekConvention::WORLD`] is returned when there is none.
+    ///
+    /// 中文: 获取某个语言环境的习惯，如`en-US`、`ar_EG`或`zh-Hans-CN`。
+    /// 使用其中的地区子标签，没有地区子标签时返回[`WeekConvention::WORLD`]
+    pub fn for_locale(locale: &str) -> WeekConvention {
+        locale
+            .split(['-', '_'])
+            .skip(1)
+            .find(|subtag| subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+            .map(Self::for_region)
+            .unwrap_or(Self::WORLD)
+    }
+
+    /// English: The first day of the week
+    ///
+    /// 中文: 一周的第一天
+    pub fn first_day(&self) -> Weekday {
+        self.first_day
+    }
+
+    /// English: The weekend days
+    ///
+    /// 中文: 周末
+    pub fn weekend(&self) -> &'static [Weekday] {
+        self.weekend
+    }
+
+    /// English: The minimal number of days in the first week of the year
+    ///
+    /// 中文: 一年中第一周最少包含的天数
+    pub fn min_days_in_first_week(&self) -> u8 {
+        self.min_days_in_first_week
+    }
+
+    /// English: Whether the weekday is a weekend day
+    ///
+    /// 中文: 给定的星期是否是周末
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend.contains(&weekday)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_region() {
+        let convention = WeekConvention::for_region("us");
+        assert_eq!(convention.first_day(), Weekday::Sun);
+        assert_eq!(convention.weekend(), &[Weekday::Sat, Weekday::Sun]);
+        assert_eq!(convention.min_days_in_first_week(), 1);
+
+        let convention = WeekConvention::for_region("EG");
+        assert_eq!(convention.first_day(), Weekday::Sat);
+        assert!(convention.is_weekend(Weekday::Fri));
+        assert!(!convention.is_weekend(Weekday::Sun));
+
+        let convention = WeekConvention::for_region("DE");
+        assert_eq!(convention.first_day(), Weekday::Mon);
+        assert_eq!(convention.min_days_in_first_week(), 4);
+
+        assert_eq!(WeekConvention::for_region("ZZ"), WeekConvention::WORLD);
+    }
+
+    #[test]
+    fn test_for_locale() {
+        assert_eq!(
+            WeekConvention::for_locale("en-US"),
+            WeekConvention::for_region("US")
+        );
+        assert_eq!(
+            WeekConvention::for_locale("ar_SA"),
+            WeekConvention::for_region("SA")
+        );
+        assert_eq!(
+            WeekConvention::for_locale("zh-Hans-CN"),
+            WeekConvention::for_region("CN")
+        );
+        assert_eq!(WeekConvention::for_locale("fr"), WeekConvention::WORLD);
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::error::DateUtilsError;
+use crate::utils::is_leap_year;
+
+/// English: A calendar year, such as `2024`
+///
+/// 中文: 一个日历年，如`2024`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(i32);
+
+impl Year {
+    /// English: Create a year, panic when the year is out of range
+    ///
+    /// 中文: 创建年份，超出范围时panic
+    pub fn new(year: i32) -> Self {
+        Self::try_new(year).unwrap()
+    }
+
+    /// English: Create a year, return an error when the year is out of range
+    ///
+    /// 中文: 创建年份，超出范围时返回错误
+    pub fn try_new(year: i32) -> Result<Self, DateUtilsError> {
+        NaiveDate::from_ymd_opt(year, 12, 31)
+            .map(|_| Year(year))
+            .ok_or(DateUtilsError::OutOfRange)
+    }
+
+    /// English: Get the number of the year
+    ///
+    /// 中文: 获取年份的数值
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+
+    /// English: Whether the year is a leap year
+    ///
+    /// 中文: 是否是闰年
+    pub fn is_leap(&self) -> bool {
+        is_leap_year(self.0)
+    }
+
+    /// English: Get the first day of the year
+    ///
+    /// 中文: 获取该年的第一天
+    pub fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.0, 1, 1).unwrap()
+    }
+
+    /// English: Get the last day of the year
+    ///
+    /// 中文: 获取该年的最后一天
+    pub fn last_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.0, 12, 31).unwrap()
+    }
+
+    /// English: Whether the date is in the year
+    ///
+    /// 中文: 日期是否在该年中
+    pub fn contains(&self, date: &NaiveDate) -> bool {
+        date.year() == self.0
+    }
+
+    /// English: Iterate over the days of the year
+    ///
+    /// 中文: 遍历该年的每一天
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> {
+        let last_day = self.last_day();
+        self.first_day()
+            .iter_days()
+            .take_while(move |date| *date <= last_day)
+    }
+
+    /// English: Get the next year
+    ///
+    /// 中文: 获取下一年
+    pub fn succ(&self) -> Year {
+        *self + 1
+    }
+
+    /// English: Get the previous year
+    ///
+    /// 中文: 获取上一年
+    pub fn pred(&self) -> Year {
+        *self - 1
+    }
+
+    /// English: Add years, return `None` when the result is out of range
+    ///
+    /// 中文: 增加年数，结果超出范围时返回`None`
+    pub fn checked_add(&self, years: i32) -> Option<Year> {
+        Year::try_new(self.0.checked_add(years)?).ok()
+    }
+
+    /// English: Subtract years, return `None` when the result is out of range
+    ///
+    /// 中文: 减少年数，结果超出范围时返回`None`
+    pub fn checked_sub(&self, years: i32) -> Option<Year> {
+        Year::try_new(self.0.checked_sub(years)?).ok()
+    }
+}
+
+impl From<NaiveDate> for Year {
+    fn from(date: NaiveDate) -> Self {
+        Year(date.year())
+    }
+}
+
+impl Add<i32> for Year {
+    type Output = Year;
+
+    /// 结果超出范围时panic，使用`checked_add`可以避免panic
+    fn add(self, years: i32) -> Self::Output {
+        self.checked_add(years).unwrap()
+    }
+}
+
+impl Sub<i32> for Year {
+    type Output = Year;
+
+    /// 结果超出范围时panic，使用`checked_sub`可以避免panic
+    fn sub(self, years: i32) -> Self::Output {
+        self.checked_sub(years).unwrap()
+    }
+}
+
+impl Sub<Year> for Year {
+    type Output = i64;
+
+    /// 两年之间相差的年数
+    fn sub(self, other: Year) -> Self::Output {
+        self.0 as i64 - other.0 as i64
+    }
+}
+
+impl Display for Year {
+    /// 默认输出`2024`的形式，使用`{:#}`时输出`2024年`的形式
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}年", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl FromStr for Year {
+    type Err = DateUtilsError;
+
+    /// 支持`2024`和`2024年`的形式
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let text = input.trim();
+        text.strip_suffix('年')
+            .unwrap_or(text)
+            .parse::<i32>()
+            .ok()
+            .and_then(|year| Year::try_new(year).ok())
+            .ok_or_else(|| DateUtilsError::Parse(input.to_string()))
+    }
+}
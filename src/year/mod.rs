@@ -1,4 +1,13 @@
-use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+use std::ops::Sub;
+
+use crate::error::DateUtilsError;
+use crate::overflow::{OverflowPolicy, shift_months};
+use crate::rounding::{Rounding, fraction, nanos};
+
+mod calendar_year;
+
+pub use calendar_year::Year;
 
 /// English: The helper of year
 ///
@@ -40,6 +49,14 @@ pub trait YearHelper {
     ///
     /// 中文: 计算日期年差
     fn diff_years(&self, other: &Self) -> i32;
+    /// English: Get the number of years between the given dates with the given rounding method.
+    ///
+    /// 中文: 按照指定的取整方式计算两个日期之间的年数
+    fn diff_years_with(&self, other: &Self, rounding: Rounding) -> i32;
+    /// English: Get the number of years between the given dates, including the fractional part.
+    ///
+    /// 中文: 计算两个日期之间的年数，包含小数部分
+    fn diff_years_f64(&self, other: &Self) -> f64;
     /// English: Return the last day of a year for the given date.
     ///
     /// 中文: 获取一年的最后一天
@@ -54,6 +71,30 @@ pub trait YearHelper {
     fn sub_years_opt(&self, n: i32) -> Option<Self>
     where
         Self: Sized;
+    /// English: Add the n years, returning an error instead of panicking when the result does not exist.
+    ///
+    /// 中文: 加上n年，结果不存在时返回错误而不是panic
+    fn try_add_years(&self, n: i32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
+    /// English: Subtract the n years, returning an error instead of panicking when the result does not exist.
+    ///
+    /// 中文: 减去n年，结果不存在时返回错误而不是panic
+    fn try_sub_years(&self, n: i32) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
+    /// English: Add the n years with the given overflow policy.
+    ///
+    /// 中文: 按照指定的溢出策略加上n年
+    fn add_years_with(&self, n: i32, policy: OverflowPolicy) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
+    /// English: Subtract the n years with the given overflow policy.
+    ///
+    /// 中文: 按照指定的溢出策略减去n年
+    fn sub_years_with(&self, n: i32, policy: OverflowPolicy) -> Result<Self, DateUtilsError>
+    where
+        Self: Sized;
 }
 
 impl YearHelper for NaiveDateTime {
@@ -87,7 +128,9 @@ impl YearHelper for NaiveDateTime {
     where
         Self: Sized,
     {
-        self.with_year(self.year() + n)
+        self.year()
+            .checked_add(n)
+            .and_then(|year| self.with_year(year))
     }
 
     fn diff_calendar_years(&self, other: &Self) -> i32 {
@@ -108,6 +151,16 @@ impl YearHelper for NaiveDateTime {
         self.date().diff_years(&other.date()) + adder
     }
 
+    fn diff_years_with(&self, other: &Self, rounding: Rounding) -> i32 {
+        let (whole, remainder, unit) = year_fraction(self, other);
+        rounding.apply(whole, remainder, unit) as i32
+    }
+
+    fn diff_years_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder, unit) = year_fraction(self, other);
+        fraction(whole, remainder, unit)
+    }
+
     fn last_day_of_year(&self) -> Self {
         self.end_of_year().date().and_hms_opt(23, 59, 59).unwrap()
     }
@@ -122,6 +175,24 @@ impl YearHelper for NaiveDateTime {
     {
         self.add_years_opt(-n)
     }
+
+    fn try_add_years(&self, n: i32) -> Result<Self, DateUtilsError> {
+        self.add_years_opt(n)
+            .ok_or_else(|| add_years_error(self.year(), n))
+    }
+
+    fn try_sub_years(&self, n: i32) -> Result<Self, DateUtilsError> {
+        let n = n.checked_neg().ok_or(DateUtilsError::OutOfRange)?;
+        self.try_add_years(n)
+    }
+
+    fn add_years_with(&self, n: i32, policy: OverflowPolicy) -> Result<Self, DateUtilsError> {
+        shift_months(&self.date(), n as i64 * 12, policy).map(|date| date.and_time(self.time()))
+    }
+
+    fn sub_years_with(&self, n: i32, policy: OverflowPolicy) -> Result<Self, DateUtilsError> {
+        shift_months(&self.date(), -(n as i64) * 12, policy).map(|date| date.and_time(self.time()))
+    }
 }
 
 impl YearHelper for NaiveDate {
@@ -151,7 +222,9 @@ impl YearHelper for NaiveDate {
     where
         Self: Sized,
     {
-        self.with_year(self.year() + n)
+        self.year()
+            .checked_add(n)
+            .and_then(|year| self.with_year(year))
     }
 
     fn diff_calendar_years(&self, other: &Self) -> i32 {
@@ -166,6 +239,16 @@ impl YearHelper for NaiveDate {
         }
     }
 
+    fn diff_years_with(&self, other: &Self, rounding: Rounding) -> i32 {
+        let (whole, remainder, unit) = year_fraction(self, other);
+        rounding.apply(whole, remainder, unit) as i32
+    }
+
+    fn diff_years_f64(&self, other: &Self) -> f64 {
+        let (whole, remainder, unit) = year_fraction(self, other);
+        fraction(whole, remainder, unit)
+    }
+
     fn last_day_of_year(&self) -> Self {
         self.end_of_year()
     }
@@ -180,12 +263,61 @@ impl YearHelper for NaiveDate {
     {
         self.add_years_opt(-n)
     }
+
+    fn try_add_years(&self, n: i32) -> Result<Self, DateUtilsError> {
+        self.add_years_opt(n)
+            .ok_or_else(|| add_years_error(self.year(), n))
+    }
+
+    fn try_sub_years(&self, n: i32) -> Result<Self, DateUtilsError> {
+        let n = n.checked_neg().ok_or(DateUtilsError::OutOfRange)?;
+        self.try_add_years(n)
+    }
+
+    fn add_years_with(&self, n: i32, policy: OverflowPolicy) -> Result<Self, DateUtilsError> {
+        shift_months(self, n as i64 * 12, policy)
+    }
+
+    fn sub_years_with(&self, n: i32, policy: OverflowPolicy) -> Result<Self, DateUtilsError> {
+        shift_months(self, -(n as i64) * 12, policy)
+    }
+}
+
+/// 计算两个日期之间的整年数，以及剩余的部分和下一年的长度
+fn year_fraction<T>(one: &T, other: &T) -> (i64, i128, i128)
+where
+    T: YearHelper + Ord + Copy + Sub<Output = Duration>,
+{
+    let whole = one.diff_years(other);
+    let step = if one < other { -1 } else { 1 };
+    let anchor = other.add_years_with(whole, OverflowPolicy::Clamp).unwrap();
+    let next = other
+        .add_years_with(whole + step, OverflowPolicy::Clamp)
+        .unwrap();
+    (
+        whole as i64,
+        nanos(*one - anchor),
+        nanos(next - anchor).abs(),
+    )
+}
+
+/// 加年失败时，区分结果超出范围和结果日期不存在(如2月29日)两种情况
+fn add_years_error(year: i32, n: i32) -> DateUtilsError {
+    match year
+        .checked_add(n)
+        .and_then(|year| NaiveDate::from_ymd_opt(year, 1, 1))
+    {
+        Some(_) => DateUtilsError::InvalidDate,
+        None => DateUtilsError::OutOfRange,
+    }
 }
 
 #[cfg(test)]
 mod test {
     use chrono::NaiveDate;
 
+    use crate::error::DateUtilsError;
+    use crate::rounding::Rounding;
     use crate::test::get_time_opt;
     use crate::year::YearHelper;
 
@@ -197,6 +329,44 @@ mod test {
         assert_eq!(after, expect);
     }
 
+    #[test]
+    fn test_try_add_years() {
+        let leap_day = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+        assert_eq!(
+            leap_day.try_add_years(4),
+            Ok(NaiveDate::from_ymd_opt(2004, 2, 29).unwrap())
+        );
+        assert_eq!(leap_day.try_add_years(1), Err(DateUtilsError::InvalidDate));
+        assert_eq!(
+            leap_day.try_add_years(i32::MAX),
+            Err(DateUtilsError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_datetime_try_sub_years() {
+        let date = get_time_opt(2000, 6, 6, 6, 6, 6).unwrap();
+        let actual = get_time_opt(1998, 6, 6, 6, 6, 6).unwrap();
+        assert_eq!(date.try_sub_years(2), Ok(actual));
+        assert_eq!(
+            date.try_sub_years(i32::MIN),
+            Err(DateUtilsError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_diff_years_with() {
+        let one = get_time_opt(2020, 1, 1, 0, 0, 0).unwrap();
+        let other = get_time_opt(2021, 7, 2, 12, 0, 0).unwrap();
+        assert_eq!(other.diff_years_f64(&one), 1.5);
+        assert_eq!(other.diff_years_with(&one, Rounding::Trunc), 1);
+        assert_eq!(other.diff_years_with(&one, Rounding::Round), 2);
+        let one = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let other = NaiveDate::from_ymd_opt(2021, 4, 1).unwrap();
+        assert_eq!(one.diff_years_with(&other, Rounding::Floor), -2);
+        assert_eq!(one.diff_years_with(&other, Rounding::Ceil), -1);
+    }
+
     #[test]
     fn test_since_year() {
         let before = NaiveDate::from_ymd_opt(2020, 3, 4).unwrap();
@@ -1,5 +1,5 @@
 #[cfg(feature = "month")]
-use date_utils::{MonthHelper, Range};
+use date_utils::{MonthHelper, OverflowPolicy, Range, Rounding, YearMonth};
 use super::*;
 #[test]
 #[cfg(feature = "month")]
@@ -90,6 +90,62 @@ fn test_diff_in_months() {
 }
 #[test]
 #[cfg(feature = "month")]
+fn test_diff_months_across_year() {
+    let one = calc_date(2024, 2, 1);
+    let other = calc_date(2023, 11, 1);
+    assert_eq!(one.diff_calendar_months(&other), 3);
+    assert_eq!(other.diff_calendar_months(&one), 3);
+    assert_eq!(one.diff_months(&other), 3);
+    assert_eq!(other.diff_months(&one), 3);
+    let one = calc_date(2024, 1, 15);
+    let other = calc_date(2023, 12, 20);
+    assert_eq!(one.diff_calendar_months(&other), 1);
+    assert_eq!(one.diff_months(&other), 0);
+    assert_eq!(other.diff_months(&one), 0);
+}
+#[test]
+#[cfg(feature = "month")]
+fn test_diff_months_with_sign() {
+    let one = calc_date(2024, 1, 1);
+    let other = calc_date(2024, 3, 16);
+    assert_eq!(other.diff_months_with(&one, Rounding::Floor), 2);
+    assert_eq!(other.diff_months_with(&one, Rounding::Ceil), 3);
+    assert_eq!(one.diff_months_with(&other, Rounding::Trunc), -2);
+    assert_eq!(one.diff_months_with(&other, Rounding::Floor), -3);
+    assert_eq!(one.diff_months_with(&other, Rounding::Ceil), -2);
+    assert_eq!(one.diff_months_with(&other, Rounding::Round), -2);
+    assert_eq!(one.diff_months_f64(&other), -other.diff_months_f64(&one));
+    assert!(one.diff_months_f64(&other) < -2.0);
+}
+#[test]
+#[cfg(feature = "month")]
+fn test_diff_months_with_across_year() {
+    let later = calc_date(2024, 2, 1);
+    let earlier = calc_date(2023, 11, 1);
+    assert_eq!(later.diff_months_with(&earlier, Rounding::Trunc), 3);
+    assert_eq!(earlier.diff_months_with(&later, Rounding::Trunc), -3);
+    assert_eq!(later.diff_months_f64(&earlier), 3.0);
+    assert_eq!(earlier.diff_months_f64(&later), -3.0);
+    let later = calc_date(2024, 1, 1);
+    let earlier = calc_date(2023, 12, 17);
+    assert_eq!(later.diff_months_with(&earlier, Rounding::Ceil), 1);
+    assert_eq!(earlier.diff_months_with(&later, Rounding::Floor), -1);
+    assert_eq!(earlier.diff_months_with(&later, Rounding::Round), 0);
+    assert_eq!(later.diff_months_f64(&earlier), 15.0 / 31.0);
+    assert_eq!(earlier.diff_months_f64(&later), -15.0 / 31.0);
+}
+#[test]
+#[cfg(feature = "month")]
+fn test_diff_months_with_trunc_matches_diff_months() {
+    let later = calc_date(2024, 3, 16);
+    let earlier = calc_date(2023, 11, 20);
+    let months = later.diff_months(&earlier);
+    assert_eq!(earlier.diff_months(&later), months);
+    assert_eq!(later.diff_months_with(&earlier, Rounding::Trunc), months);
+    assert_eq!(earlier.diff_months_with(&later, Rounding::Trunc), -months);
+}
+#[test]
+#[cfg(feature = "month")]
 fn test_each_weekend() {
     let date = calc_date(2024, 6, 1);
     let weekends = date.each_weekend();
@@ -237,3 +293,65 @@ fn test_last_day_of_month() {
     let result = date.last_day_of_month();
     assert_eq!(result, calc_date(2023, 1, 31));
 }
+#[test]
+#[cfg(feature = "month")]
+fn test_add_months_with() {
+    let one = calc_date(2023, 1, 31);
+    assert_eq!(
+        one.add_months_with(1, OverflowPolicy::Clamp),
+        Ok(calc_date(2023, 2, 28))
+    );
+    assert_eq!(
+        one.add_months_with(1, OverflowPolicy::Overflow),
+        Ok(calc_date(2023, 3, 3))
+    );
+    assert!(one.add_months_with(1, OverflowPolicy::Error).is_err());
+}
+#[test]
+#[cfg(feature = "month")]
+fn test_sub_months_with_sticky_end_of_month() {
+    let one = calc_date(2023, 4, 30);
+    let result = one.sub_months_with(1, OverflowPolicy::StickyEndOfMonth);
+    assert_eq!(result, Ok(calc_date(2023, 3, 31)));
+}
+
+#[test]
+#[cfg(feature = "month")]
+fn test_year_month() {
+    let month = YearMonth::from(calc_date(2024, 2, 15));
+    assert_eq!(month, YearMonth::new(2024, 2));
+    assert_eq!(month.first_day(), calc_date(2024, 2, 1));
+    assert_eq!(month.last_day(), calc_date(2024, 2, 29));
+    assert!(month.contains(&calc_date(2024, 2, 29)));
+    assert!(!month.contains(&calc_date(2023, 2, 1)));
+    assert_eq!(month.days().count(), 29);
+    assert_eq!(month + 11, YearMonth::new(2025, 1));
+    assert_eq!(month - 2, YearMonth::new(2023, 12));
+    assert_eq!(YearMonth::new(2025, 1) - month, 11);
+    assert!(month.pred() < month.succ());
+    assert!(YearMonth::try_new(2024, 13).is_err());
+}
+
+#[test]
+#[cfg(feature = "month")]
+fn test_year_month_checked_add_and_sub() {
+    let month = YearMonth::new(2024, 2);
+    assert_eq!(month.checked_add(11), Some(YearMonth::new(2025, 1)));
+    assert_eq!(month.checked_sub(-11), Some(YearMonth::new(2025, 1)));
+    assert_eq!(month.checked_sub(2), Some(YearMonth::new(2023, 12)));
+    assert_eq!(month.checked_add(i32::MAX), None);
+    assert_eq!(month.checked_sub(i32::MAX), None);
+    assert_eq!(month.checked_sub(i32::MIN), None);
+}
+
+#[test]
+#[cfg(feature = "month")]
+fn test_year_month_format_and_parse() {
+    let month = YearMonth::new(2024, 5);
+    assert_eq!(month.to_string(), "2024-05");
+    assert_eq!(format!("{:#}", month), "2024年5月");
+    assert_eq!("2024-05".parse::<YearMonth>(), Ok(month));
+    assert_eq!("2024年5月".parse::<YearMonth>(), Ok(month));
+    assert!("2024-5".parse::<YearMonth>().is_err());
+    assert!("2024-13".parse::<YearMonth>().is_err());
+}
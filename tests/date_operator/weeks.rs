@@ -1,8 +1,8 @@
 #[cfg(test)]
 use super::*;
-use chrono::Weekday;
+use chrono::{Datelike, Weekday};
 #[cfg(all(feature = "week",feature = "range"))]
-use date_utils::{Range, WeekHelper};
+use date_utils::{Range, WeekHelper, YearWeek};
 use std::iter::zip;
 
 #[test]
@@ -601,3 +601,237 @@ fn test_previous_monday_and_so_on() {
     assert_eq!(previous_saturday, calc_date(2023, 7, 8));
     assert_eq!(previous_sunday, calc_date(2023, 7, 9));
 }
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_week_of_year() {
+    let date = calc_date(2021, 1, 3);
+    assert_eq!(date.week_of_year(), 53);
+    assert_eq!(date.week_of_year0(), 2);
+    let date = calc_date(2024, 12, 30);
+    assert_eq!(date.week_of_year(), 1);
+    assert_eq!(date.week_of_year0(), 1);
+    assert_eq!(date.week_of_year_with(Weekday::Sat, 7), 52);
+}
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_weeks_in_year_with() {
+    assert_eq!(
+        calc_date(2020, 6, 1).weeks_in_year_with(Weekday::Mon, 4),
+        53
+    );
+    assert_eq!(
+        calc_date(2021, 6, 1).weeks_in_year_with(Weekday::Mon, 4),
+        52
+    );
+    assert_eq!(
+        calc_date(2022, 6, 1).weeks_in_year_with(Weekday::Sun, 1),
+        53
+    );
+}
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_begin_of_week_of_year() {
+    assert_eq!(
+        calc_date(2021, 6, 1).begin_of_week_of_year(1),
+        calc_date(2021, 1, 4)
+    );
+    assert_eq!(
+        calc_date(2024, 6, 1).begin_of_week_of_year(10),
+        calc_date(2024, 3, 4)
+    );
+    assert_eq!(
+        calc_date(2023, 6, 1).begin_of_week_of_year_with(1, Weekday::Sun, 1),
+        calc_date(2023, 1, 1)
+    );
+}
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_begin_of_week_of_year_across_years() {
+    let date = calc_date(2024, 12, 30);
+    assert_eq!(date.week_of_year(), 1);
+    assert_eq!(date.begin_of_week_of_year(1), calc_date(2024, 12, 30));
+    let date = calc_date(2021, 1, 2);
+    assert_eq!(date.week_of_year(), 53);
+    assert_eq!(date.begin_of_week_of_year(53), calc_date(2020, 12, 28));
+    assert_eq!(date.begin_of_week_of_year(1), calc_date(2019, 12, 30));
+    assert_eq!(
+        date.begin_of_week_of_year_with_opt(1, Weekday::Sun, 1),
+        Some(calc_date(2020, 12, 27))
+    );
+}
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_begin_of_week_of_year_opt() {
+    let date = calc_date(2021, 6, 1);
+    assert_eq!(date.begin_of_week_of_year_opt(0), None);
+    assert_eq!(
+        date.begin_of_week_of_year_opt(52),
+        Some(calc_date(2021, 12, 27))
+    );
+    assert_eq!(date.begin_of_week_of_year_opt(53), None);
+    assert_eq!(
+        calc_date(2020, 6, 1).begin_of_week_of_year_opt(53),
+        Some(calc_date(2020, 12, 28))
+    );
+}
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_set_week_of_year() {
+    assert_eq!(
+        calc_date(2024, 1, 3).set_week_of_year(10),
+        calc_date(2024, 3, 6)
+    );
+    assert_eq!(
+        calc_date(2023, 6, 15).set_week_of_year_with(1, Weekday::Sun, 1),
+        calc_date(2023, 1, 5)
+    );
+}
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_set_week_of_year_opt() {
+    let date = calc_date(2024, 6, 5);
+    assert_eq!(date.set_week_of_year_opt(60), None);
+    assert_eq!(date.set_week_of_year_opt(0), None);
+    assert_eq!(date.set_week_of_year_opt(53), None);
+    assert_eq!(date.set_week_of_year_opt(52), Some(calc_date(2024, 12, 25)));
+    assert_eq!(
+        calc_date(2021, 1, 2).set_week_of_year_opt(1),
+        Some(calc_date(2020, 1, 4))
+    );
+    assert_eq!(date.set_week_of_year_with_opt(53, Weekday::Sun, 1), None);
+}
+#[test]
+#[should_panic]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_set_week_of_year_out_of_range() {
+    calc_date(2024, 6, 5).set_week_of_year(60);
+}
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_week_in_locale() {
+    let date = calc_date(2023, 10, 6);
+    assert!(date.is_weekend_in("ar-EG"));
+    assert!(date.is_workday_in("en-US"));
+    assert!(calc_date(2023, 10, 8).is_workday_in("he-IL"));
+    let date = calc_date(2023, 10, 4);
+    assert_eq!(date.begin_of_week_in("en-US"), calc_date(2023, 10, 1));
+    assert_eq!(date.begin_of_week_in("ar-EG"), calc_date(2023, 9, 30));
+    assert_eq!(date.begin_of_week_in("de-DE"), calc_date(2023, 10, 2));
+    let date = calc_date(2023, 10, 7);
+    assert_eq!(date.week_of_month_in("en-US"), 1);
+    assert_eq!(date.week_of_month_in("de-DE"), 2);
+    let date = calc_date(2021, 1, 3);
+    assert_eq!(date.week_of_year_in("de-DE"), 53);
+    assert_eq!(date.week_of_year_in("en-US"), 2);
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_year_week() {
+    let week = YearWeek::from(calc_date(2021, 1, 3));
+    assert_eq!(week, YearWeek::new(2020, 53));
+    assert_eq!(week.first_day(), calc_date(2020, 12, 28));
+    assert_eq!(week.last_day(), calc_date(2021, 1, 3));
+    assert!(week.contains(&calc_date(2020, 12, 31)));
+    assert_eq!(week.days().count(), 7);
+    assert_eq!(week + 1, YearWeek::new(2021, 1));
+    assert_eq!(week - 52, YearWeek::new(2020, 1));
+    assert_eq!(YearWeek::new(2021, 2) - week, 2);
+    assert!(week.pred() < week.succ());
+    assert!(YearWeek::try_new(2021, 53).is_err());
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_year_week_checked_add_and_sub() {
+    let week = YearWeek::new(2020, 53);
+    assert_eq!(week.checked_add(1), Some(YearWeek::new(2021, 1)));
+    assert_eq!(week.checked_sub(52), Some(YearWeek::new(2020, 1)));
+    assert_eq!(week.checked_sub(-1), Some(YearWeek::new(2021, 1)));
+    assert_eq!(week.checked_add(i32::MAX), None);
+    assert_eq!(week.checked_sub(i32::MAX), None);
+    assert_eq!(week.checked_sub(i32::MIN), None);
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_year_week_format_and_parse() {
+    let week = YearWeek::new(2024, 5);
+    assert_eq!(week.to_string(), "2024-W05");
+    assert_eq!(format!("{:#}", week), "2024年第5周");
+    assert_eq!("2024-W05".parse::<YearWeek>(), Ok(week));
+    assert_eq!("2024年第5周".parse::<YearWeek>(), Ok(week));
+    assert!("2024-W5".parse::<YearWeek>().is_err());
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_nth_weekday_of_month() {
+    let date = NaiveDate::nth_weekday_of_month(2024, 11, Weekday::Thu, 4);
+    assert_eq!(date, Some(calc_date(2024, 11, 28)));
+    let date = NaiveDate::nth_weekday_of_month(2024, 5, Weekday::Mon, -1);
+    assert_eq!(date, Some(calc_date(2024, 5, 27)));
+    let date = NaiveDate::nth_weekday_of_month(2024, 2, Weekday::Thu, 5);
+    assert_eq!(date, Some(calc_date(2024, 2, 29)));
+    assert_eq!(
+        NaiveDate::nth_weekday_of_month(2024, 2, Weekday::Fri, 5),
+        None
+    );
+    assert_eq!(
+        NaiveDate::nth_weekday_of_month(2024, 2, Weekday::Fri, 0),
+        None
+    );
+    assert_eq!(
+        NaiveDate::nth_weekday_of_month(2024, 13, Weekday::Fri, 1),
+        None
+    );
+    let date = calc_date(2024, 5, 15);
+    assert_eq!(
+        date.set_nth_weekday_of_month(Weekday::Sun, 2),
+        Some(calc_date(2024, 5, 12))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_weekday_ordinal_in_month() {
+    let date = calc_date(2024, 5, 14);
+    assert_eq!(date.weekday_ordinal_in_month(), 2);
+    assert!(!date.is_last_weekday_of_month());
+    let date = calc_date(2024, 5, 28);
+    assert_eq!(date.weekday_ordinal_in_month(), 4);
+    assert!(date.is_last_weekday_of_month());
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_count_weekday_between() {
+    let start = calc_date(2024, 5, 1);
+    let end = calc_date(2024, 5, 31);
+    assert_eq!(
+        NaiveDate::count_weekday_between(&start, &end, Weekday::Wed),
+        5
+    );
+    assert_eq!(
+        NaiveDate::count_weekday_between(&start, &end, Weekday::Mon),
+        4
+    );
+    assert_eq!(
+        NaiveDate::count_weekday_between(&start, &start, Weekday::Wed),
+        1
+    );
+    assert_eq!(
+        NaiveDate::count_weekday_between(&end, &start, Weekday::Wed),
+        0
+    );
+    let count = start
+        .iter_days()
+        .take(400)
+        .filter(|date| date.weekday() == Weekday::Sat)
+        .count();
+    let end = start + chrono::Days::new(399);
+    assert_eq!(
+        NaiveDate::count_weekday_between(&start, &end, Weekday::Sat),
+        count as u64
+    );
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "year")]
-use date_utils::YearHelper;
+use date_utils::{OverflowPolicy, Year, YearHelper};
 use super::calc_date;
 
 #[test]
@@ -38,3 +38,52 @@ fn test_is_leap_year() {
     let date = calc_date(1900, 1, 1);
     assert!(!date.is_leap_year());
 }
+#[test]
+#[cfg(feature = "year")]
+fn test_add_years_with_leap_day() {
+    let date = calc_date(2024, 2, 29);
+    assert_eq!(
+        date.add_years_with(1, OverflowPolicy::Clamp),
+        Ok(calc_date(2025, 2, 28))
+    );
+    assert_eq!(
+        date.add_years_with(1, OverflowPolicy::Overflow),
+        Ok(calc_date(2025, 3, 1))
+    );
+    assert!(date.add_years_with(1, OverflowPolicy::Error).is_err());
+    let date = calc_date(2023, 2, 28);
+    assert_eq!(
+        date.add_years_with(1, OverflowPolicy::StickyEndOfMonth),
+        Ok(calc_date(2024, 2, 29))
+    );
+}
+
+#[test]
+#[cfg(feature = "year")]
+fn test_year() {
+    let year = Year::from(calc_date(2024, 5, 15));
+    assert_eq!(year, Year::new(2024));
+    assert!(year.is_leap());
+    assert_eq!(year.first_day(), calc_date(2024, 1, 1));
+    assert_eq!(year.last_day(), calc_date(2024, 12, 31));
+    assert!(year.contains(&calc_date(2024, 12, 31)));
+    assert_eq!(year.days().count(), 366);
+    assert_eq!(year + 1, Year::new(2025));
+    assert_eq!(year.pred() - year.succ(), -2);
+    assert_eq!(year.to_string(), "2024");
+    assert_eq!(format!("{:#}", year), "2024年");
+    assert_eq!("2024年".parse::<Year>(), Ok(year));
+    assert!(Year::try_new(i32::MAX).is_err());
+}
+
+#[test]
+#[cfg(feature = "year")]
+fn test_year_checked_add_and_sub() {
+    let year = Year::new(2024);
+    assert_eq!(year.checked_add(1), Some(Year::new(2025)));
+    assert_eq!(year.checked_sub(-1), Some(Year::new(2025)));
+    assert_eq!(year.checked_sub(24), Some(Year::new(2000)));
+    assert_eq!(year.checked_add(i32::MAX), None);
+    assert_eq!(year.checked_sub(i32::MIN), None);
+    assert_eq!(year.checked_add(1_000_000), None);
+}
@@ -1,7 +1,5 @@
 #[cfg(feature = "hour")]
-use date_utils::HourHelper;
-#[cfg(feature = "year")]
-use date_utils::YearHelper;
+use date_utils::{HourHelper, Rounding};
 use super::*;
 #[test]
 #[cfg(feature = "hour")]
@@ -85,3 +83,15 @@ fn test_sub_hours_opt() {
     let result = one.sub_hours_opt(6);
     assert_eq!(result, Some(actual));
 }
+#[test]
+#[cfg(feature = "hour")]
+fn test_diff_hours_with() {
+    let one = calc_datetime(2000, 1, 1, 0, 0, 0);
+    let other = calc_datetime(2000, 1, 1, 6, 30, 0);
+    assert_eq!(other.diff_hours_with(&one, Rounding::Trunc), 6);
+    assert_eq!(other.diff_hours_with(&one, Rounding::Ceil), 7);
+    assert_eq!(other.diff_hours_with(&one, Rounding::Round), 7);
+    assert_eq!(one.diff_hours_with(&other, Rounding::Floor), -7);
+    assert_eq!(one.diff_hours_with(&other, Rounding::Ceil), -6);
+    assert_eq!(other.diff_hours_f64(&one), 6.5);
+}
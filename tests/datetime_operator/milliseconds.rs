@@ -56,3 +56,12 @@ fn test_set_millisecond() {
     let actual = result.millisecond();
     assert_eq!(actual, 100);
 }
+#[test]
+#[cfg(feature = "millisecond")]
+fn test_try_set_millisecond() {
+    let date = calc_datetime(2023, 6, 11, 0, 0, 1);
+    let result = date.try_set_millisecond(100).map(|date| date.millisecond());
+    assert_eq!(result, Ok(100));
+    assert!(date.try_set_millisecond(1000).is_err());
+    assert!(date.try_set_millisecond(-1).is_err());
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "minute")]
-use date_utils::MinuteHelper;
+use date_utils::{MinuteHelper, Rounding};
 use super::*;
 #[test]
 #[cfg(feature = "minute")]
@@ -77,3 +77,14 @@ fn test_sub_minutes_opt() {
     let result = one.sub_minutes_opt(30);
     assert_eq!(result, Some(other));
 }
+
+#[test]
+#[cfg(feature = "minute")]
+fn test_diff_minutes_with() {
+    let one = calc_datetime(2000, 1, 1, 12, 0, 0);
+    let other = calc_datetime(2000, 1, 1, 12, 30, 20);
+    assert_eq!(other.diff_minutes_with(&one, Rounding::Round), 30);
+    assert_eq!(other.diff_minutes_with(&one, Rounding::Ceil), 31);
+    assert_eq!(one.diff_minutes_with(&other, Rounding::Floor), -31);
+    assert_eq!(one.diff_minutes_f64(&other), -30.0 - 1.0 / 3.0);
+}
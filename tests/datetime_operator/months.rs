@@ -1,5 +1,5 @@
 #[cfg(feature = "month")]
-use date_utils::MonthHelper;
+use date_utils::{MonthHelper, Rounding};
 use super::calc_datetime;
 
 #[test]
@@ -211,3 +211,16 @@ fn test_last_day_of_month() {
     let expected = calc_datetime(2023, 1, 31, 0, 0, 0);
     assert_eq!(actual, expected);
 }
+#[test]
+#[cfg(feature = "month")]
+fn test_diff_months_f64() {
+    let date = calc_datetime(2023, 1, 1, 0, 0, 0);
+    let other = calc_datetime(2023, 3, 16, 12, 0, 0);
+    assert_eq!(other.diff_months_f64(&date), 2.5);
+    assert_eq!(other.diff_months_with(&date, Rounding::Round), 3);
+    assert_eq!(other.diff_months_with(&date, Rounding::Floor), 2);
+    assert_eq!(date.diff_months_f64(&other), -2.5);
+    assert_eq!(date.diff_months_with(&other, Rounding::Round), -3);
+    assert_eq!(date.diff_months_with(&other, Rounding::Floor), -3);
+    assert_eq!(date.diff_months_with(&other, Rounding::Ceil), -2);
+}
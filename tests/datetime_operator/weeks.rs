@@ -1,7 +1,7 @@
 #[cfg(test)]
 #[cfg(all(feature = "week",feature = "range"))]
 use date_utils::{Range, WeekHelper};
-use chrono::Weekday;
+use chrono::{NaiveDateTime, Weekday};
 use std::iter::zip;
 use crate::calc_datetime;
 
@@ -545,3 +545,68 @@ fn test_prev_monday_and_so_on() {
     assert_eq!(prev_saturday, calc_datetime(2023, 6, 10, 0, 0, 0));
     assert_eq!(prev_sunday, calc_datetime(2023, 6, 11, 0, 0, 0));
 }
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_week_of_year() {
+    let date = calc_datetime(2021, 1, 3, 10, 0, 0);
+    assert_eq!(date.week_of_year(), 53);
+    assert_eq!(date.week_of_year0(), 2);
+    assert_eq!(date.weeks_in_year_with(Weekday::Mon, 4), 52);
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_begin_and_set_week_of_year() {
+    let date = calc_datetime(2021, 6, 1, 12, 30, 0);
+    assert_eq!(
+        date.begin_of_week_of_year(1),
+        calc_datetime(2021, 1, 4, 0, 0, 0)
+    );
+    assert_eq!(
+        date.set_week_of_year(1),
+        calc_datetime(2021, 1, 5, 12, 30, 0)
+    );
+    let date = calc_datetime(2024, 12, 31, 12, 30, 0);
+    assert_eq!(
+        date.begin_of_week_of_year(1),
+        calc_datetime(2024, 12, 30, 0, 0, 0)
+    );
+    assert_eq!(date.begin_of_week_of_year_opt(53), None);
+    assert_eq!(date.set_week_of_year_opt(0), None);
+    assert_eq!(
+        date.set_week_of_year_opt(2),
+        Some(calc_datetime(2025, 1, 7, 12, 30, 0))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_week_in_locale() {
+    let date = calc_datetime(2023, 10, 4, 12, 0, 0);
+    assert_eq!(
+        date.begin_of_week_in("en-US"),
+        calc_datetime(2023, 10, 1, 0, 0, 0)
+    );
+    assert!(date.is_workday_in("ar-EG"));
+    assert!(!calc_datetime(2023, 10, 6, 12, 0, 0).is_workday_in("ar-EG"));
+}
+
+#[test]
+#[cfg(all(feature = "week",feature = "range"))]
+fn test_nth_weekday_of_month() {
+    let date = NaiveDateTime::nth_weekday_of_month(2024, 11, Weekday::Thu, -1);
+    assert_eq!(date, Some(calc_datetime(2024, 11, 28, 0, 0, 0)));
+    let date = calc_datetime(2024, 5, 15, 8, 30, 0);
+    assert_eq!(
+        date.set_nth_weekday_of_month(Weekday::Mon, 1),
+        Some(calc_datetime(2024, 5, 6, 8, 30, 0))
+    );
+    assert_eq!(date.weekday_ordinal_in_month(), 3);
+    assert!(!date.is_last_weekday_of_month());
+    let end = calc_datetime(2024, 5, 31, 0, 0, 0);
+    assert_eq!(
+        NaiveDateTime::count_weekday_between(&date, &end, Weekday::Wed),
+        3
+    );
+}
@@ -2,7 +2,7 @@
 #[cfg(feature = "quarter")]
 mod decade {
     use chrono::{NaiveDate, NaiveDateTime};
-    use date_utils::DecadeHelper;
+    use date_utils::{CenturyConvention, CenturyHelper, DecadeHelper, MillenniumHelper};
 
     #[test]
     fn test_begin_of_decade() {
@@ -31,6 +31,13 @@ mod decade {
         assert_eq!(result, 2020);
     }
 
+    #[test]
+    fn test_begin_and_end_of_decade_from_leap_day() {
+        let date = calc_date(2012, 2, 29);
+        assert_eq!(date.begin_of_decade(), calc_date(2010, 1, 1));
+        assert_eq!(date.end_of_decade(), calc_date(2019, 12, 31));
+    }
+
     #[test]
     fn test_begin_of_decade_time() {
         let date = calc_date_time(2023, 1, 1, 1, 1, 1);
@@ -59,6 +66,75 @@ mod decade {
         assert_eq!(result, 2020);
     }
 
+    #[test]
+    fn test_negative_decade() {
+        let date = calc_date(-5, 6, 1);
+        assert_eq!(date.decade(), -10);
+        assert_eq!(date.begin_of_decade(), calc_date(-10, 1, 1));
+        assert_eq!(date.end_of_decade(), calc_date(-1, 12, 31));
+        assert!(!date.is_same_decade(&calc_date(5, 6, 1)));
+    }
+
+    #[test]
+    fn test_add_and_diff_decades() {
+        let date = calc_date(2012, 2, 29);
+        assert_eq!(date.add_decades(1), calc_date(2022, 2, 28));
+        assert_eq!(date.sub_decades(2), calc_date(1992, 2, 29));
+        assert_eq!(date.add_decades_opt(i32::MAX), None);
+        assert!(date.is_same_decade(&calc_date(2019, 12, 31)));
+        assert_eq!(calc_date(2032, 2, 28).diff_decades(&date), 1);
+        assert_eq!(
+            calc_date(2032, 2, 28).diff_decades(&calc_date(2012, 3, 1)),
+            1
+        );
+        let datetime = calc_date_time(2023, 5, 1, 8, 30, 0);
+        assert_eq!(
+            datetime.add_decades(1),
+            calc_date_time(2033, 5, 1, 8, 30, 0)
+        );
+    }
+
+    #[test]
+    fn test_century() {
+        let date = calc_date(2000, 6, 1);
+        assert_eq!(date.century(), 20);
+        assert_eq!(date.begin_of_century(), calc_date(1901, 1, 1));
+        assert_eq!(date.end_of_century(), calc_date(2000, 12, 31));
+        assert_eq!(date.century_with(CenturyConvention::Popular), 21);
+        assert_eq!(
+            date.begin_of_century_with(CenturyConvention::Popular),
+            calc_date(2000, 1, 1)
+        );
+        assert_eq!(
+            date.end_of_century_with(CenturyConvention::Popular),
+            calc_date(2099, 12, 31)
+        );
+        assert_eq!(calc_date(0, 1, 1).century(), 0);
+        let datetime = calc_date_time(1999, 5, 1, 8, 30, 0);
+        assert_eq!(
+            datetime.end_of_century(),
+            calc_date_time(2000, 12, 31, 23, 59, 59)
+        );
+    }
+
+    #[test]
+    fn test_millennium() {
+        let date = calc_date(2000, 6, 1);
+        assert_eq!(date.millennium(), 2);
+        assert_eq!(date.begin_of_millennium(), calc_date(1001, 1, 1));
+        assert_eq!(date.end_of_millennium(), calc_date(2000, 12, 31));
+        assert_eq!(date.millennium_with(CenturyConvention::Popular), 3);
+        assert_eq!(
+            date.begin_of_millennium_with(CenturyConvention::Popular),
+            calc_date(2000, 1, 1)
+        );
+        let datetime = calc_date_time(2001, 5, 1, 8, 30, 0);
+        assert_eq!(
+            datetime.begin_of_millennium(),
+            calc_date_time(2001, 1, 1, 0, 0, 0)
+        );
+    }
+
     fn calc_date(year: i32, month: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day).unwrap()
     }
@@ -0,0 +1,121 @@
+#[cfg(test)]
+#[cfg(feature = "fiscal")]
+mod fiscal_tests {
+    use chrono::NaiveDate;
+    use date_utils::{DateUtilsError, FiscalCalendar, FiscalPeriod, FiscalYearNaming, Quarter};
+
+    #[test]
+    fn test_us_federal() {
+        let calendar = FiscalCalendar::US_FEDERAL;
+        assert_eq!(calendar.fiscal_year(&calc_date(2023, 10, 1)), 2024);
+        assert_eq!(
+            calendar.fiscal_quarter(&calc_date(2023, 10, 1)),
+            Quarter::Q1
+        );
+        assert_eq!(calendar.fiscal_year(&calc_date(2023, 9, 30)), 2023);
+        assert_eq!(
+            calendar.fiscal_quarter(&calc_date(2023, 9, 30)),
+            Quarter::Q4
+        );
+        let date = calc_date(2024, 5, 1);
+        assert_eq!(calendar.begin_of_fiscal_year(&date), calc_date(2023, 10, 1));
+        assert_eq!(calendar.end_of_fiscal_year(&date), calc_date(2024, 9, 30));
+        assert_eq!(
+            calendar.begin_of_fiscal_quarter(&date),
+            calc_date(2024, 4, 1)
+        );
+        assert_eq!(
+            calendar.end_of_fiscal_quarter(&date),
+            calc_date(2024, 6, 30)
+        );
+        assert_eq!(calendar.fiscal_year_start(2024), calc_date(2023, 10, 1));
+    }
+
+    #[test]
+    fn test_uk_tax() {
+        let calendar = FiscalCalendar::UK_TAX;
+        assert_eq!(calendar.fiscal_year(&calc_date(2024, 4, 5)), 2023);
+        assert_eq!(calendar.fiscal_quarter(&calc_date(2024, 4, 5)), Quarter::Q4);
+        assert_eq!(calendar.fiscal_year(&calc_date(2024, 4, 6)), 2024);
+        assert_eq!(calendar.fiscal_quarter(&calc_date(2024, 7, 5)), Quarter::Q1);
+        let date = calc_date(2024, 4, 6);
+        assert_eq!(calendar.end_of_fiscal_quarter(&date), calc_date(2024, 7, 5));
+        assert_eq!(calendar.end_of_fiscal_year(&date), calc_date(2025, 4, 5));
+    }
+
+    #[test]
+    fn test_japan() {
+        let calendar = FiscalCalendar::JAPAN;
+        assert_eq!(calendar.fiscal_year(&calc_date(2025, 3, 31)), 2024);
+        assert_eq!(
+            calendar.fiscal_quarter(&calc_date(2025, 3, 31)),
+            Quarter::Q4
+        );
+        assert_eq!(calendar.fiscal_year_start(2024), calc_date(2024, 4, 1));
+    }
+
+    #[test]
+    fn test_new() {
+        let calendar = FiscalCalendar::new(1, 1, FiscalYearNaming::EndYear);
+        assert_eq!(calendar.fiscal_year(&calc_date(2024, 6, 1)), 2024);
+        let calendar = FiscalCalendar::new(1, 31, FiscalYearNaming::StartYear);
+        assert_eq!(
+            calendar.end_of_fiscal_quarter(&calc_date(2024, 2, 1)),
+            calc_date(2024, 4, 29)
+        );
+        assert_eq!(
+            FiscalCalendar::try_new(2, 29, FiscalYearNaming::StartYear),
+            Err(DateUtilsError::InvalidDate)
+        );
+        assert!(FiscalCalendar::try_new(13, 1, FiscalYearNaming::StartYear).is_err());
+    }
+
+    #[test]
+    fn test_diff_fiscal_quarters() {
+        let calendar = FiscalCalendar::US_FEDERAL;
+        let one = calc_date(2024, 10, 1);
+        let other = calc_date(2023, 9, 30);
+        assert_eq!(calendar.diff_fiscal_quarters(&one, &other), 5);
+        assert_eq!(calendar.diff_fiscal_quarters(&other, &one), -5);
+    }
+
+    #[test]
+    fn test_quarters() {
+        let calendar = FiscalCalendar::JAPAN;
+        let periods = calendar
+            .quarters(&calc_date(2024, 3, 15), &calc_date(2024, 10, 1))
+            .collect::<Vec<_>>();
+        assert_eq!(periods.len(), 4);
+        assert_eq!(
+            periods[0],
+            FiscalPeriod {
+                fiscal_year: 2023,
+                quarter: Some(Quarter::Q4),
+                first_day: calc_date(2024, 1, 1),
+                last_day: calc_date(2024, 3, 31),
+            }
+        );
+        assert_eq!(periods[3].quarter, Some(Quarter::Q3));
+        assert_eq!(periods[3].last_day, calc_date(2024, 12, 31));
+    }
+
+    #[test]
+    fn test_years() {
+        let calendar = FiscalCalendar::US_FEDERAL;
+        let periods = calendar
+            .years(&calc_date(2023, 1, 1), &calc_date(2024, 12, 31))
+            .collect::<Vec<_>>();
+        let years = periods
+            .iter()
+            .map(|period| period.fiscal_year)
+            .collect::<Vec<_>>();
+        assert_eq!(years, vec![2023, 2024, 2025]);
+        assert_eq!(periods[0].first_day, calc_date(2022, 10, 1));
+        assert_eq!(periods[0].last_day, calc_date(2023, 9, 30));
+        assert_eq!(periods[0].quarter, None);
+    }
+
+    fn calc_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+#[cfg(feature = "half_year")]
+mod half_year_tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use date_utils::{Half, HalfYearHelper};
+
+    #[test]
+    fn test_half() {
+        assert_eq!(calc_date(2024, 6, 30).half(), Half::H1);
+        assert_eq!(calc_date(2024, 7, 1).half(), Half::H2);
+        assert_eq!(Half::H2.to_string(), "H2");
+        assert_eq!(format!("{:#}", Half::H1), "上半年");
+    }
+
+    #[test]
+    fn test_begin_and_end_of_half() {
+        let date = calc_date(2024, 5, 15);
+        assert_eq!(date.begin_of_half(), calc_date(2024, 1, 1));
+        assert_eq!(date.end_of_half(), calc_date(2024, 6, 30));
+        let datetime = calc_datetime(2024, 9, 15, 8, 30, 0);
+        assert_eq!(datetime.begin_of_half(), calc_datetime(2024, 7, 1, 0, 0, 0));
+        assert_eq!(
+            datetime.end_of_half(),
+            calc_datetime(2024, 12, 31, 23, 59, 59)
+        );
+    }
+
+    #[test]
+    fn test_is_same_half() {
+        let date = calc_date(2024, 1, 1);
+        assert!(date.is_same_half(&calc_date(2024, 6, 30)));
+        assert!(!date.is_same_half(&calc_date(2024, 7, 1)));
+        assert!(!date.is_same_half(&calc_date(2023, 1, 1)));
+    }
+
+    #[test]
+    fn test_add_and_sub_halves() {
+        let date = calc_date(2024, 8, 31);
+        assert_eq!(date.add_halves(1), calc_date(2025, 2, 28));
+        assert_eq!(date.sub_halves(3), calc_date(2023, 2, 28));
+        assert_eq!(NaiveDate::MAX.add_halves_opt(1), None);
+        assert_eq!(NaiveDate::MIN.sub_halves_opt(1), None);
+        let datetime = calc_datetime(2024, 3, 31, 8, 30, 0);
+        assert_eq!(datetime.add_halves(1), calc_datetime(2024, 9, 30, 8, 30, 0));
+    }
+
+    #[test]
+    fn test_diff_halves() {
+        let date = calc_date(2024, 7, 1);
+        let other = calc_date(2024, 1, 2);
+        assert_eq!(date.diff_halves(&other), 0);
+        assert_eq!(date.diff_calendar_halves(&other), 1);
+        assert_eq!(other.diff_calendar_halves(&calc_date(2022, 12, 31)), 3);
+        let datetime = calc_datetime(2025, 1, 1, 0, 0, 0);
+        assert_eq!(datetime.diff_halves(&calc_datetime(2024, 1, 1, 0, 0, 0)), 2);
+        assert_eq!(
+            calc_datetime(2024, 1, 1, 0, 0, 0).diff_halves(&datetime),
+            -2
+        );
+    }
+
+    #[test]
+    fn test_diff_halves_sign() {
+        let later = calc_date(2024, 2, 1);
+        let earlier = calc_date(2023, 11, 1);
+        assert_eq!(later.diff_halves(&earlier), 0);
+        assert_eq!(earlier.diff_halves(&later), 0);
+        let later = calc_date(2024, 2, 1);
+        let earlier = calc_date(2023, 1, 1);
+        assert_eq!(later.diff_halves(&earlier), 2);
+        assert_eq!(earlier.diff_halves(&later), -2);
+        assert_eq!(earlier.diff_calendar_halves(&later), -2);
+    }
+
+    fn calc_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn calc_datetime(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> NaiveDateTime {
+        calc_date(year, month, day)
+            .and_hms_opt(hour, minute, second)
+            .unwrap()
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+#[cfg(feature = "week")]
+mod iso_week_tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use date_utils::{DateUtilsError, IsoWeekHelper};
+
+    #[test]
+    fn test_iso_week_year() {
+        let date = calc_date(2024, 12, 30);
+        assert_eq!(date.iso_week_year(), 2025);
+        assert_eq!(date.iso_week(), 1);
+        let date = calc_date(2021, 1, 3);
+        assert_eq!(date.iso_week_year(), 2020);
+        assert_eq!(date.iso_week(), 53);
+    }
+
+    #[test]
+    fn test_begin_and_end_of_iso_week_year() {
+        let date = calc_date(2021, 1, 3);
+        assert_eq!(date.begin_of_iso_week_year(), calc_date(2019, 12, 30));
+        assert_eq!(date.end_of_iso_week_year(), calc_date(2021, 1, 3));
+        let datetime = calc_datetime(2024, 6, 1, 12);
+        assert_eq!(
+            datetime.end_of_iso_week_year(),
+            calc_date(2024, 12, 29).and_hms_opt(23, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_iso_weeks_in_year() {
+        assert_eq!(calc_date(2020, 6, 1).iso_weeks_in_year(), 53);
+        assert_eq!(calc_date(2024, 6, 1).iso_weeks_in_year(), 52);
+    }
+
+    #[test]
+    fn test_add_iso_week_years() {
+        let date = calc_date(2024, 1, 31);
+        assert_eq!(date.add_iso_week_years(1), calc_date(2025, 1, 29));
+        let date = calc_date(2021, 1, 3);
+        assert_eq!(date.add_iso_week_years(1), calc_date(2022, 1, 2));
+        assert_eq!(date.add_iso_week_years(-5), calc_date(2016, 1, 3));
+        assert_eq!(NaiveDate::MAX.add_iso_week_years_opt(1), None);
+    }
+
+    #[test]
+    fn test_format() {
+        let date = calc_date(2024, 1, 31);
+        assert_eq!(date.format_iso_week_date(), "2024-W05-3");
+        assert_eq!(date.format_ordinal_date(), "2024-031");
+        let datetime = calc_datetime(2024, 2, 4, 8);
+        assert_eq!(datetime.format_iso_week_date(), "2024-W05-7T08:00:00");
+        assert_eq!(datetime.format_ordinal_date(), "2024-035T08:00:00");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            NaiveDate::parse_iso_week_date("2024-W05-3"),
+            Ok(calc_date(2024, 1, 31))
+        );
+        assert_eq!(
+            NaiveDate::parse_ordinal_date("2024-035"),
+            Ok(calc_date(2024, 2, 4))
+        );
+        assert_eq!(
+            NaiveDateTime::parse_iso_week_date("2024-W05-7T08:00:00"),
+            Ok(calc_datetime(2024, 2, 4, 8))
+        );
+        assert_eq!(
+            NaiveDateTime::parse_ordinal_date("2024-035"),
+            Ok(calc_datetime(2024, 2, 4, 0))
+        );
+        for input in [
+            "2024-W53-1",
+            "2024-W5-3",
+            "2024-W05-8",
+            "2024-35",
+            "2023-366",
+        ] {
+            assert!(NaiveDate::parse_iso_week_date(input).is_err());
+            assert_eq!(
+                NaiveDate::parse_ordinal_date(input),
+                Err(DateUtilsError::Parse(input.to_string()))
+            );
+        }
+    }
+
+    fn calc_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn calc_datetime(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+        calc_date(year, month, day).and_hms_opt(hour, 0, 0).unwrap()
+    }
+}
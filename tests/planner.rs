@@ -0,0 +1,101 @@
+#[cfg(test)]
+#[cfg(feature = "planner")]
+mod planner_tests {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    use date_utils::{
+        PosixTz, WeekConvention, WorkingHours, common_working_windows, convert_to_zones,
+    };
+
+    #[test]
+    fn test_convert_to_zones() {
+        let zones = [zone("CST-8"), zone("EST5EDT,M3.2.0,M11.1.0")];
+        let instant = calc_datetime(2024, 7, 1, 12, 0);
+        let times = convert_to_zones(&instant, &zones);
+        assert_eq!(times[0].naive_local(), calc_datetime(2024, 7, 1, 20, 0));
+        assert_eq!(times[1].naive_local(), calc_datetime(2024, 7, 1, 8, 0));
+        assert_eq!(times[1].offset().abbreviation(), "EDT");
+    }
+
+    #[test]
+    fn test_common_working_windows() {
+        let participants = [
+            office("CST-8", 9, 18),
+            office("GMT0BST,M3.5.0/1,M10.5.0", 9, 18),
+        ];
+        // 2024年1月8日是周一
+        let windows = common_working_windows(
+            calc_date(2024, 1, 6)..=calc_date(2024, 1, 14),
+            &participants,
+        );
+        assert_eq!(windows.len(), 5);
+        assert_eq!(windows[0], utc(2024, 1, 8, 9)..utc(2024, 1, 8, 10));
+        assert_eq!(windows[4], utc(2024, 1, 12, 9)..utc(2024, 1, 12, 10));
+        // 夏令时期间伦敦的工作时间提前一个小时
+        let windows =
+            common_working_windows(calc_date(2024, 7, 1)..=calc_date(2024, 7, 1), &participants);
+        assert_eq!(windows, vec![utc(2024, 7, 1, 8)..utc(2024, 7, 1, 10)]);
+    }
+
+    #[test]
+    fn test_common_working_windows_weekend() {
+        let participants = [
+            office("CST-8", 9, 18),
+            office("EET-2", 9, 18).with_convention(WeekConvention::for_region("EG")),
+        ];
+        // 开罗的周末为周五和周六
+        let windows = common_working_windows(
+            calc_date(2024, 1, 8)..=calc_date(2024, 1, 14),
+            &participants,
+        );
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[3], utc(2024, 1, 11, 7)..utc(2024, 1, 11, 10));
+
+        let participants = [
+            office("CST-8", 9, 18),
+            office("EST5EDT,M3.2.0,M11.1.0", 9, 18),
+        ];
+        let windows = common_working_windows(
+            calc_date(2024, 1, 8)..=calc_date(2024, 1, 14),
+            &participants,
+        );
+        assert!(windows.is_empty());
+    }
+
+    #[test]
+    fn test_common_working_windows_overnight() {
+        let participants = [
+            WorkingHours::from((zone("CST-8"), time(22)..time(6))),
+            office("UTC0", 10, 20),
+        ];
+        let windows =
+            common_working_windows(calc_date(2024, 1, 9)..=calc_date(2024, 1, 9), &participants);
+        assert_eq!(windows, vec![utc(2024, 1, 9, 14)..utc(2024, 1, 9, 20)]);
+        assert!(participants[0].is_working_day(&calc_date(2024, 1, 9)));
+    }
+
+    fn office(zone_text: &str, start: u32, end: u32) -> WorkingHours<PosixTz> {
+        WorkingHours::new(zone(zone_text), time(start)..time(end))
+    }
+
+    fn zone(text: &str) -> PosixTz {
+        text.parse().unwrap()
+    }
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        calc_datetime(year, month, day, hour, 0).and_utc()
+    }
+
+    fn calc_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn calc_datetime(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        calc_date(year, month, day)
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+}
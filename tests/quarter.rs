@@ -2,7 +2,9 @@
 #[cfg(feature = "quarter")]
 mod quarter_tests {
     use chrono::NaiveDate;
-    use date_utils::{Quarter, QuarterHelper};
+    use date_utils::{
+        DateUtilsError, OverflowPolicy, Quarter, QuarterHelper, Rounding, YearQuarter,
+    };
 
     #[test]
     fn test_begin_of_quarter() {
@@ -72,6 +74,134 @@ mod quarter_tests {
         let other = calc_date(2019, 2, 1);
         assert_eq!(date.diff_quarters(&other), 1);
     }
+    #[test]
+    fn test_begin_and_end_of_quarter_at_month_end() {
+        let date = calc_date(2019, 5, 31);
+        assert_eq!(date.begin_of_quarter(), calc_date(2019, 4, 1));
+        let date = calc_date(2019, 8, 31);
+        assert_eq!(date.end_of_quarter(), calc_date(2019, 9, 30));
+    }
+    #[test]
+    fn test_try_add_quarters() {
+        let date = calc_date(2019, 3, 1);
+        assert_eq!(date.try_add_quarters(1), Ok(calc_date(2019, 6, 1)));
+        assert_eq!(
+            NaiveDate::MAX.try_add_quarters(1),
+            Err(DateUtilsError::OutOfRange)
+        );
+    }
+    #[test]
+    fn test_add_quarters_with() {
+        let date = calc_date(2019, 11, 30);
+        let result = date.add_quarters_with(1, OverflowPolicy::Overflow);
+        assert_eq!(result, Ok(calc_date(2020, 3, 1)));
+        let result = date.sub_quarters_with(1, OverflowPolicy::StickyEndOfMonth);
+        assert_eq!(result, Ok(calc_date(2019, 8, 31)));
+    }
+    #[test]
+    fn test_diff_quarters_with() {
+        let date = calc_date(2019, 5, 1);
+        let other = calc_date(2019, 1, 1);
+        assert_eq!(date.diff_quarters_with(&other, Rounding::Trunc), 1);
+        assert_eq!(date.diff_quarters_with(&other, Rounding::Round), 1);
+        assert_eq!(date.diff_quarters_with(&other, Rounding::Ceil), 2);
+        let date = calc_date(2019, 7, 1);
+        assert_eq!(date.diff_quarters_with(&other, Rounding::Ceil), 2);
+        assert_eq!(date.diff_quarters_f64(&other), 2.0);
+        assert_eq!(other.diff_quarters_f64(&date), -2.0);
+        let date = calc_date(2019, 5, 1);
+        assert_eq!(other.diff_quarters_with(&date, Rounding::Trunc), -1);
+        assert_eq!(other.diff_quarters_with(&date, Rounding::Round), -1);
+        assert_eq!(other.diff_quarters_with(&date, Rounding::Floor), -2);
+        assert_eq!(other.diff_quarters_with(&date, Rounding::Ceil), -1);
+        assert!(other.diff_quarters_f64(&date) < -1.0);
+    }
+    #[test]
+    fn test_diff_quarters_with_across_year() {
+        let later = calc_date(2024, 2, 1);
+        let earlier = calc_date(2023, 11, 1);
+        assert_eq!(later.diff_quarters_with(&earlier, Rounding::Trunc), 1);
+        assert_eq!(earlier.diff_quarters_with(&later, Rounding::Trunc), -1);
+        assert_eq!(later.diff_quarters_f64(&earlier), 1.0);
+        assert_eq!(earlier.diff_quarters_f64(&later), -1.0);
+        let later = calc_date(2024, 1, 1);
+        let earlier = calc_date(2023, 12, 1);
+        assert_eq!(later.diff_quarters_with(&earlier, Rounding::Ceil), 1);
+        assert_eq!(earlier.diff_quarters_with(&later, Rounding::Floor), -1);
+        let later = later.and_hms_opt(0, 0, 0).unwrap();
+        let earlier = earlier.and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(later.diff_quarters_with(&earlier, Rounding::Trunc), 0);
+        assert_eq!(earlier.diff_quarters_f64(&later), -1.0 / 3.0);
+    }
+    #[test]
+    fn test_datetime_quarter() {
+        let datetime = calc_date(2019, 5, 31).and_hms_opt(8, 30, 0).unwrap();
+        assert_eq!(datetime.quarter(), Quarter::Q2);
+        assert_eq!(
+            datetime.begin_of_quarter(),
+            calc_date(2019, 4, 1).and_hms_opt(0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            datetime.end_of_quarter(),
+            calc_date(2019, 6, 30).and_hms_opt(23, 59, 59).unwrap()
+        );
+        assert_eq!(
+            datetime.add_quarters(1),
+            calc_date(2019, 8, 31).and_hms_opt(8, 30, 0).unwrap()
+        );
+        assert_eq!(
+            datetime.sub_quarters(1),
+            calc_date(2019, 2, 28).and_hms_opt(8, 30, 0).unwrap()
+        );
+        let other = calc_date(2019, 1, 1).and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(datetime.diff_quarters(&other), 1);
+        assert_eq!(datetime.diff_calendar_quarters(&other), 1);
+        assert_eq!(datetime.diff_quarters_with(&other, Rounding::Ceil), 2);
+        assert_eq!(other.diff_quarters_with(&datetime, Rounding::Floor), -2);
+        assert_eq!(other.diff_quarters_with(&datetime, Rounding::Ceil), -1);
+    }
+
+    #[test]
+    fn test_quarter_enum() {
+        assert_eq!(Quarter::from_month(5), Some(Quarter::Q2));
+        assert_eq!(Quarter::from_month(13), None);
+        assert_eq!(Quarter::Q3.number(), 3);
+        assert_eq!(Quarter::Q3.first_month(), 7);
+        assert_eq!(Quarter::Q4.months(), [10, 11, 12]);
+        assert_eq!(Quarter::Q4.succ(), Quarter::Q1);
+        assert_eq!(Quarter::Q1.pred(), Quarter::Q4);
+        assert_eq!(Quarter::Q1.to_string(), "Q1");
+        assert_eq!(format!("{:#}", Quarter::Q1), "第一季度");
+        assert_eq!("q2".parse::<Quarter>().unwrap(), Quarter::Q2);
+        assert_eq!("3".parse::<Quarter>().unwrap(), Quarter::Q3);
+        assert_eq!("第四季度".parse::<Quarter>().unwrap(), Quarter::Q4);
+        assert!("Q5".parse::<Quarter>().is_err());
+    }
+
+    #[test]
+    fn test_year_quarter() {
+        let quarter = YearQuarter::from(calc_date(2024, 2, 29));
+        assert_eq!(quarter, YearQuarter::new(2024, Quarter::Q1));
+        assert_eq!(quarter.first_day(), calc_date(2024, 1, 1));
+        assert_eq!(quarter.last_day(), calc_date(2024, 3, 31));
+        assert!(quarter.contains(&calc_date(2024, 3, 31)));
+        assert!(!quarter.contains(&calc_date(2023, 3, 31)));
+        assert_eq!(quarter + 4, YearQuarter::new(2025, Quarter::Q1));
+        assert_eq!(quarter - 1, YearQuarter::new(2023, Quarter::Q4));
+        assert_eq!(quarter.succ() - quarter.pred(), 2);
+        assert_eq!(quarter.to_string(), "2024-Q1");
+        assert_eq!(format!("{:#}", quarter), "2024年第一季度");
+        assert_eq!("2024-Q1".parse::<YearQuarter>().unwrap(), quarter);
+        assert_eq!("2024年第一季度".parse::<YearQuarter>().unwrap(), quarter);
+        assert!("2024-Q0".parse::<YearQuarter>().is_err());
+
+        let quarters: Vec<_> = YearQuarter::new(2023, Quarter::Q3)
+            .iter_to(YearQuarter::new(2024, Quarter::Q2))
+            .map(|quarter| quarter.to_string())
+            .collect();
+        assert_eq!(quarters, ["2023-Q3", "2023-Q4", "2024-Q1", "2024-Q2"]);
+    }
+
     fn calc_date(year: i32, month: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day).unwrap()
     }
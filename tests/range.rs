@@ -67,6 +67,20 @@ mod ranges {
         assert_eq!(iter.last(), Some(calc_date(2022, 1, 6)));
     }
 
+    #[test]
+    fn test_halves() {
+        let date = calc_date(2022, 8, 31);
+        let halves: Vec<_> = date.halves().take(3).collect();
+        assert_eq!(
+            halves,
+            [
+                calc_date(2022, 7, 1),
+                calc_date(2023, 1, 1),
+                calc_date(2023, 7, 1)
+            ]
+        );
+    }
+
     fn calc_date(year: i32, month: u32, day: u32) -> NaiveDate {
         NaiveDate::from_ymd_opt(year, month, day).unwrap()
     }
@@ -156,3 +170,53 @@ mod times {
             .unwrap()
     }
 }
+
+#[cfg(test)]
+#[cfg(all(feature = "range", feature = "tz", feature = "day"))]
+mod zoned_times {
+    use chrono::{DateTime, NaiveDate, TimeZone, Timelike};
+    use date_utils::{DayHelper, PosixTz, TimeRange};
+
+    #[test]
+    fn test_hours_spring_forward() {
+        let begin = calc_zoned(3, 10, 0).begin_of_day();
+        let end = begin.end_of_day();
+        let hours: Vec<u32> = begin
+            .hours_with_iter(&end)
+            .map(|time| time.hour())
+            .collect();
+        assert_eq!(hours.len(), 23);
+        assert_eq!(&hours[..4], &[0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_hours_fall_back() {
+        let begin = calc_zoned(11, 3, 0).begin_of_day();
+        let end = begin.end_of_day();
+        let hours: Vec<u32> = begin
+            .hours_with_iter(&end)
+            .map(|time| time.hour())
+            .collect();
+        assert_eq!(hours.len(), 25);
+        assert_eq!(&hours[..4], &[0, 1, 1, 2]);
+    }
+
+    #[test]
+    fn test_minutes_and_seconds() {
+        let time = calc_zoned(3, 10, 1) + chrono::Duration::seconds(59 * 60 + 30);
+        let mut minutes = time.minutes();
+        assert_eq!(minutes.next().unwrap().minute(), 59);
+        assert_eq!(minutes.next().unwrap().hour(), 3);
+        let end = time.clone() + chrono::Duration::seconds(10);
+        assert_eq!(time.seconds_with_iter(&end).count(), 11);
+    }
+
+    fn calc_zoned(month: u32, day: u32, hour: u32) -> DateTime<PosixTz> {
+        let zone: PosixTz = "EST5EDT,M3.2.0,M11.1.0".parse().unwrap();
+        let local = NaiveDate::from_ymd_opt(2024, month, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap();
+        zone.from_local_datetime(&local).earliest().unwrap()
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+#[cfg(feature = "retail")]
+mod retail_tests {
+    use chrono::{NaiveDate, Weekday};
+    use date_utils::{
+        DateUtilsError, FiscalYearNaming, LeapWeekPlacement, Quarter, RetailCalendar,
+        RetailPattern, YearEndRule,
+    };
+
+    #[test]
+    fn test_nrf_year() {
+        let calendar = RetailCalendar::NRF;
+        let date = calc_date(2023, 6, 1);
+        assert_eq!(calendar.retail_year(&date), 2023);
+        assert_eq!(calendar.begin_of_retail_year(&date), calc_date(2023, 1, 29));
+        assert_eq!(calendar.end_of_retail_year(&date), calc_date(2024, 2, 3));
+        assert_eq!(calendar.weeks_in_retail_year(&date), 53);
+        assert_eq!(calendar.weeks_in_retail_year(&calc_date(2022, 6, 1)), 52);
+        assert_eq!(calendar.retail_year(&calc_date(2023, 1, 28)), 2022);
+    }
+
+    #[test]
+    fn test_nrf_period() {
+        let calendar = RetailCalendar::NRF;
+        let date = calc_date(2023, 3, 15);
+        assert_eq!(calendar.retail_period(&date), 2);
+        assert_eq!(calendar.retail_quarter(&date), Quarter::Q1);
+        assert_eq!(calendar.retail_week(&date), 7);
+        assert_eq!(
+            calendar.begin_of_retail_period(&date),
+            calc_date(2023, 2, 26)
+        );
+        assert_eq!(calendar.end_of_retail_period(&date), calc_date(2023, 4, 1));
+        assert_eq!(
+            calendar.end_of_retail_quarter(&date),
+            calc_date(2023, 4, 29)
+        );
+        assert_eq!(calendar.begin_of_retail_week(&date), calc_date(2023, 3, 12));
+        assert_eq!(calendar.end_of_retail_week(&date), calc_date(2023, 3, 18));
+    }
+
+    #[test]
+    fn test_nrf_leap_week() {
+        let calendar = RetailCalendar::NRF;
+        let date = calc_date(2024, 2, 3);
+        assert_eq!(calendar.retail_week(&date), 53);
+        assert_eq!(calendar.retail_period(&date), 12);
+        assert_eq!(calendar.retail_quarter(&date), Quarter::Q4);
+        assert_eq!(
+            calendar.begin_of_retail_period(&date),
+            calc_date(2023, 12, 31)
+        );
+        assert_eq!(
+            calendar.begin_of_retail_quarter(&date),
+            calc_date(2023, 10, 29)
+        );
+        assert_eq!(calendar.end_of_retail_quarter(&date), calc_date(2024, 2, 3));
+    }
+
+    #[test]
+    fn test_last_weekday_of_month() {
+        let calendar = RetailCalendar::new(
+            RetailPattern::P445,
+            12,
+            Weekday::Sat,
+            YearEndRule::LastWeekdayOfMonth,
+            LeapWeekPlacement::FirstPeriod,
+            FiscalYearNaming::EndYear,
+        );
+        assert_eq!(calendar.retail_year(&calc_date(2023, 1, 1)), 2023);
+        let date = calc_date(2022, 1, 1);
+        assert_eq!(calendar.retail_year(&date), 2022);
+        assert_eq!(
+            calendar.begin_of_retail_year(&date),
+            calc_date(2021, 12, 26)
+        );
+        assert_eq!(calendar.weeks_in_retail_year(&date), 53);
+        assert_eq!(calendar.retail_period(&calc_date(2022, 1, 29)), 1);
+        assert_eq!(calendar.retail_period(&calc_date(2022, 1, 30)), 2);
+        assert_eq!(calendar.end_of_retail_quarter(&date), calc_date(2022, 4, 2));
+    }
+
+    #[test]
+    fn test_periods_13() {
+        let calendar = RetailCalendar::new(
+            RetailPattern::Periods13,
+            1,
+            Weekday::Sat,
+            YearEndRule::NearestToMonthEnd,
+            LeapWeekPlacement::LastPeriod,
+            FiscalYearNaming::StartYear,
+        );
+        let date = calc_date(2024, 2, 3);
+        assert_eq!(calendar.retail_period(&date), 13);
+        assert_eq!(
+            calendar.begin_of_retail_period(&date),
+            calc_date(2023, 12, 31)
+        );
+        assert_eq!(calendar.retail_period(&calc_date(2023, 2, 25)), 1);
+    }
+
+    #[test]
+    fn test_try_new() {
+        let calendar = RetailCalendar::try_new(
+            RetailPattern::P454,
+            13,
+            Weekday::Sat,
+            YearEndRule::NearestToMonthEnd,
+            LeapWeekPlacement::LastPeriod,
+            FiscalYearNaming::StartYear,
+        );
+        assert_eq!(calendar, Err(DateUtilsError::InvalidDate));
+    }
+
+    fn calc_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
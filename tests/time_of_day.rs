@@ -0,0 +1,157 @@
+#[cfg(test)]
+#[cfg(feature = "time")]
+mod time_of_day_tests {
+    use chrono::{Duration, NaiveTime};
+    use date_utils::{DayPart, DayPartBoundaries, TimeOfDayHelper, TimeOfDayRange};
+
+    #[test]
+    fn test_add_minutes_wrapping() {
+        assert_eq!(
+            calc_time(23, 30, 0).add_minutes_wrapping(60),
+            calc_time(0, 30, 0)
+        );
+        assert_eq!(
+            calc_time(0, 30, 0).add_minutes_wrapping(-60),
+            calc_time(23, 30, 0)
+        );
+        assert_eq!(
+            calc_time(10, 0, 0).add_minutes_wrapping(1440 * 3 + 5),
+            calc_time(10, 5, 0)
+        );
+    }
+
+    #[test]
+    fn test_diff_minutes_wrapping() {
+        assert_eq!(
+            calc_time(1, 0, 0).diff_minutes_wrapping(&calc_time(23, 0, 0)),
+            120
+        );
+        assert_eq!(
+            calc_time(23, 0, 0).diff_minutes_wrapping(&calc_time(1, 0, 0)),
+            1320
+        );
+        assert_eq!(
+            calc_time(10, 0, 30).diff_minutes_wrapping(&calc_time(10, 0, 0)),
+            0
+        );
+        assert_eq!(
+            calc_time(10, 0, 0).diff_minutes_wrapping(&calc_time(10, 0, 30)),
+            1439
+        );
+    }
+
+    #[test]
+    fn test_begin_and_end_of_hour() {
+        let time = calc_time(10, 25, 13);
+        assert_eq!(time.begin_of_hour(), calc_time(10, 0, 0));
+        assert_eq!(time.end_of_hour(), calc_time(10, 59, 59));
+    }
+
+    #[test]
+    fn test_am_pm() {
+        assert!(calc_time(0, 0, 0).is_am());
+        assert!(calc_time(11, 59, 59).is_am());
+        assert!(calc_time(12, 0, 0).is_pm());
+        assert!(!calc_time(23, 0, 0).is_am());
+    }
+
+    #[test]
+    fn test_day_part() {
+        assert_eq!(calc_time(5, 0, 0).day_part(), DayPart::Morning);
+        assert_eq!(calc_time(12, 30, 0).day_part(), DayPart::Afternoon);
+        assert_eq!(calc_time(18, 0, 0).day_part(), DayPart::Evening);
+        assert_eq!(calc_time(23, 0, 0).day_part(), DayPart::Night);
+        assert_eq!(calc_time(3, 0, 0).day_part(), DayPart::Night);
+        assert_eq!(DayPart::Evening.to_string(), "evening");
+        assert_eq!(format!("{:#}", DayPart::Morning), "上午");
+
+        let boundaries = DayPartBoundaries::new(
+            calc_time(6, 0, 0),
+            calc_time(13, 0, 0),
+            calc_time(19, 0, 0),
+            calc_time(23, 0, 0),
+        );
+        assert_eq!(
+            calc_time(5, 30, 0).day_part_with(&boundaries),
+            DayPart::Night
+        );
+        assert_eq!(
+            calc_time(12, 30, 0).day_part_with(&boundaries),
+            DayPart::Morning
+        );
+        assert_eq!(boundaries.start_of(DayPart::Evening), calc_time(19, 0, 0));
+    }
+
+    #[test]
+    fn test_round_to() {
+        let quarter = Duration::minutes(15);
+        assert_eq!(calc_time(10, 7, 29).round_to(quarter), calc_time(10, 0, 0));
+        assert_eq!(calc_time(10, 7, 30).round_to(quarter), calc_time(10, 15, 0));
+        assert_eq!(calc_time(23, 55, 0).round_to(quarter), calc_time(0, 0, 0));
+        assert_eq!(
+            calc_time(10, 0, 0).round_to(Duration::hours(1)),
+            calc_time(10, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_round_to_opt() {
+        let time = calc_time(10, 7, 30);
+        assert_eq!(
+            time.round_to_opt(Duration::minutes(15)),
+            Some(calc_time(10, 15, 0))
+        );
+        assert_eq!(time.round_to_opt(Duration::zero()), None);
+        assert_eq!(time.round_to_opt(Duration::minutes(-15)), None);
+        assert_eq!(time.round_to_opt(Duration::MIN), None);
+        assert_eq!(time.round_to_opt(Duration::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_round_to_zero_step() {
+        calc_time(10, 0, 0).round_to(Duration::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_round_to_min_step() {
+        calc_time(10, 0, 0).round_to(Duration::MIN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_round_to_overflowing_step() {
+        calc_time(10, 0, 0).round_to(Duration::MAX);
+    }
+
+    #[test]
+    fn test_time_of_day_range() {
+        let night = TimeOfDayRange::new(calc_time(22, 0, 0), calc_time(6, 0, 0));
+        assert!(night.crosses_midnight());
+        assert!(night.contains(&calc_time(23, 0, 0)));
+        assert!(night.contains(&calc_time(0, 0, 0)));
+        assert!(night.contains(&calc_time(22, 0, 0)));
+        assert!(!night.contains(&calc_time(6, 0, 0)));
+        assert!(!night.contains(&calc_time(12, 0, 0)));
+        assert_eq!(night.duration(), Duration::hours(8));
+        assert_eq!(night.to_string(), "22:00-06:00");
+
+        let office = TimeOfDayRange::new(calc_time(9, 0, 0), calc_time(18, 0, 0));
+        assert!(!office.crosses_midnight());
+        assert!(office.contains(&calc_time(9, 0, 0)));
+        assert!(!office.contains(&calc_time(18, 0, 0)));
+        assert_eq!(office.duration(), Duration::hours(9));
+
+        let whole_day = TimeOfDayRange::new(calc_time(8, 0, 0), calc_time(8, 0, 0));
+        assert!(whole_day.contains(&calc_time(3, 0, 0)));
+        assert_eq!(whole_day.duration(), Duration::days(1));
+        let evening = TimeOfDayRange::new(calc_time(18, 0, 0), NaiveTime::MIN);
+        assert!(!evening.crosses_midnight());
+        assert!(evening.contains(&calc_time(23, 59, 59)));
+    }
+
+    fn calc_time(hour: u32, minute: u32, second: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, second).unwrap()
+    }
+}